@@ -41,7 +41,7 @@ fn main() {
         .add_view(&area_view)
         .add_axis_bottom(&x)
         .add_axis_left(&y)
-        .add_left_axis_label("Custom Y Axis Label")
-        .add_bottom_axis_label("Custom X Axis Label")
+        .add_left_axis_label("Custom Y Axis Label").unwrap()
+        .add_bottom_axis_label("Custom X Axis Label").unwrap()
         .save("area-chart.svg").unwrap();
 }
@@ -52,7 +52,7 @@ fn main() {
         .add_view(&scatter_view)                        // <-- add scatter view
         .add_axis_bottom(&x)
         .add_axis_left(&y)
-        .add_left_axis_label("Units of Measurement")
-        .add_bottom_axis_label("Categories")
+        .add_left_axis_label("Units of Measurement").unwrap()
+        .add_bottom_axis_label("Categories").unwrap()
         .save("composite-bar-and-scatter-chart.svg").unwrap();
 }
\ No newline at end of file
@@ -37,7 +37,7 @@ fn main() {
         .add_axis_bottom(&x)
         .add_axis_top(&x)
         .add_axis_left(&y)
-        .add_left_axis_label("Y Axis Custom Label")
-        .add_bottom_axis_label("X Axis Custom Label")
+        .add_left_axis_label("Y Axis Custom Label").unwrap()
+        .add_bottom_axis_label("X Axis Custom Label").unwrap()
         .save("horizontal-bar-chart.svg").unwrap();
 }
\ No newline at end of file
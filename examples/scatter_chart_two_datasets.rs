@@ -54,8 +54,8 @@ fn main() {
         .add_view(&scatter_view_2)
         .add_axis_bottom(&x)
         .add_axis_left(&y)
-        .add_left_axis_label("Custom X Axis Label")
-        .add_bottom_axis_label("Custom Y Axis Label")
+        .add_left_axis_label("Custom X Axis Label").unwrap()
+        .add_bottom_axis_label("Custom Y Axis Label").unwrap()
         .add_legend_at(AxisPosition::Bottom)
         .save("scatter-chart-two-datasets.svg").unwrap();
 }
\ No newline at end of file
@@ -41,7 +41,7 @@ fn main() {
         .add_view(&view)
         .add_axis_bottom(&x)
         .add_axis_left(&y)
-        .add_left_axis_label("Units of Measurement")
-        .add_bottom_axis_label("Categories")
+        .add_left_axis_label("Units of Measurement").unwrap()
+        .add_bottom_axis_label("Categories").unwrap()
         .save("vertical-bar-chart.svg").unwrap();
 }
\ No newline at end of file
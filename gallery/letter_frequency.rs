@@ -46,7 +46,7 @@ fn main() -> std::io::Result<()> {
         .add_view(&view)
         .add_axis_bottom(&x)
         .add_axis_left(&y)
-        .add_left_axis_label("Frequency (%)")
+        .add_left_axis_label("Frequency (%)").unwrap()
         .save("letter-frequency.svg").unwrap();
 
     Ok(())
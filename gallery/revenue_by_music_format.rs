@@ -54,7 +54,7 @@ fn main() -> std::io::Result<()> {
         .add_legend_at(AxisPosition::Top)
         .set_bottom_axis_tick_label_rotation(-90)
         .set_left_axis_tick_label_format(".2s")
-        .add_left_axis_label("Revenue ($)")
+        .add_left_axis_label("Revenue ($)").unwrap()
         .save("revenue-by-music-format.svg").unwrap();
 
     Ok(())
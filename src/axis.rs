@@ -1,12 +1,12 @@
-use crate::components::axis::{AxisLine, AxisTick};
+use crate::backend::svg::SvgBackend;
+use crate::backend::{Backend, GroupOptions, TextAnchor, TextStyle};
+use crate::components::axis::{AxisBreak, AxisLine, AxisTick, AxisTickGroup, Gridlines};
+use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::scales::ScaleType;
 use crate::{Chart, Scale};
 use std::string::ToString;
 use svg::node::element::Group;
-use svg::node::element::Text;
-use svg::node::Text as TextNode;
-use svg::parser::Error;
-use svg::Node;
 
 // Enum for tick label sizing
 enum TickLabel {
@@ -16,7 +16,9 @@ enum TickLabel {
 }
 
 /// Enum of possible axis positions on the chart.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum AxisPosition {
     Top,
     Right,
@@ -27,9 +29,11 @@ pub enum AxisPosition {
 /// An axis struct that represents an axis along a dimension of the chart.
 pub struct Axis {
     ticks: Vec<AxisTick>,
+    tick_groups: Vec<(String, f32, f32)>,
     tick_label_font_size: Option<usize>,
     max_tick_length: TickLabel,
     axis_line: AxisLine,
+    breaks: Vec<AxisBreak>,
     position: AxisPosition,
     label: String,
     label_rotation: isize,
@@ -47,10 +51,12 @@ impl Axis {
     ) -> Self {
         Self {
             ticks: Self::generate_ticks(scale, position),
+            tick_groups: Self::generate_tick_groups(scale),
             tick_label_font_size: None,
             max_tick_length: Self::calculate_max_tick_length(scale),
             position,
-            axis_line: Self::get_axis_line(position, chart),
+            axis_line: Self::get_axis_line(position, chart).set_gaps(scale.range_breaks()),
+            breaks: Self::generate_breaks(scale, position),
             label: String::new(),
             label_rotation: 0,
             label_format: String::new(),
@@ -131,11 +137,11 @@ impl Axis {
     /// Calculate analogue for the length of the tick labels.
     fn calculate_max_tick_length<T: ToString>(scale: &dyn Scale<T>) -> TickLabel {
         match scale.get_type() {
-            ScaleType::Band => {
+            ScaleType::Band | ScaleType::Point => {
                 match scale
-                    .get_domain()
-                    .into_iter()
-                    .map(|s| s.to_string().len())
+                    .get_ticks()
+                    .iter()
+                    .map(|tick| Self::tick_label(scale, tick).len())
                     .max()
                 {
                     Some(size) => TickLabel::Band(size),
@@ -144,12 +150,29 @@ impl Axis {
             }
             ScaleType::Linear => TickLabel::Linear(scale.domain_max()),
             ScaleType::Logarithmic => TickLabel::Linear(scale.domain_max()),
-
+            ScaleType::Symlog => TickLabel::Linear(scale.domain_max()),
+            ScaleType::Pow => TickLabel::Linear(scale.domain_max()),
+            ScaleType::Time => {
+                match scale
+                    .get_ticks()
+                    .iter()
+                    .map(|tick| Self::tick_label(scale, tick).len())
+                    .max()
+                {
+                    Some(size) => TickLabel::Band(size),
+                    None => TickLabel::Band(0),
+                }
+            }
             ScaleType::Ordinal => {
-                todo!();
-                // When ordinal scale type is implemented
-                #[allow(unreachable_code)]
-                TickLabel::Ordinal(0)
+                match scale
+                    .get_domain()
+                    .into_iter()
+                    .map(|s| s.to_string().len())
+                    .max()
+                {
+                    Some(size) => TickLabel::Ordinal(size),
+                    None => TickLabel::Ordinal(0),
+                }
             }
         }
     }
@@ -157,17 +180,17 @@ impl Axis {
     /// Calculate the y position for the label
     fn calculate_y_for_label(&self) -> i32 {
         match self.max_tick_length {
-            TickLabel::Band(characters) => {
+            TickLabel::Band(characters) | TickLabel::Ordinal(characters) => {
                 let calculated = match self.tick_label_font_size {
                     Some(font_size) => Axis::characters_to_px(characters, font_size),
                     None => Axis::characters_to_px(characters, 12),
                 };
 
                 match self.position {
-                    AxisPosition::Top => 42,
-                    AxisPosition::Bottom => 42,
-                    AxisPosition::Left => calculated,
-                    AxisPosition::Right => calculated,
+                    AxisPosition::Top => 42 + self.tick_group_tier_size(),
+                    AxisPosition::Bottom => 42 + self.tick_group_tier_size(),
+                    AxisPosition::Left => calculated + self.tick_group_tier_size(),
+                    AxisPosition::Right => calculated + self.tick_group_tier_size(),
                 }
             }
             TickLabel::Linear(upper_bound) => {
@@ -185,17 +208,71 @@ impl Axis {
                     AxisPosition::Right => calculated,
                 }
             }
-            TickLabel::Ordinal(_size) => 42,
         }
     }
 
+    /// Calculate the space taken by the tier of tick group labels, if any.
+    fn tick_group_tier_size(&self) -> i32 {
+        if self.tick_groups.is_empty() {
+            0
+        } else {
+            self.tick_label_font_size.unwrap_or(12) as i32 + 8
+        }
+    }
+
+    /// Calculate the distance from the axis line to the center of the tick group labels.
+    fn tick_group_label_offset(&self) -> usize {
+        let font_size = self.tick_label_font_size.unwrap_or(12);
+        let tick_labels = match self.position {
+            AxisPosition::Top | AxisPosition::Bottom => 16 + font_size / 2,
+            AxisPosition::Left | AxisPosition::Right => match self.max_tick_length {
+                TickLabel::Band(characters) | TickLabel::Ordinal(characters) => {
+                    Axis::characters_to_px(characters, font_size) as usize
+                }
+                TickLabel::Linear(_) => 12,
+            },
+        };
+        tick_labels + 4 + font_size / 2
+    }
+
     fn characters_to_px(characters: usize, font_size: usize) -> i32 {
         // tick space + characters * fontsize * proportion
         (12_f32 + (characters as f32 * font_size as f32 * 0.7)) as i32
     }
 
     /// Generate svg for the axis.
-    pub fn to_svg(&self) -> Result<Group, Error> {
+    pub fn to_svg(&self) -> Result<Group, ChartError> {
+        let mut backend = SvgBackend::new();
+        self.draw(&mut backend, (0_f32, 0_f32))?;
+        Ok(backend.into_group())
+    }
+
+    /// Draw gridlines of the given length at the ticks of the axis on the given backend, with the axis origin at the given position.
+    /// They are drawn separately from the axis so that they can be placed beneath the views.
+    pub fn draw_gridlines(
+        &self,
+        backend: &mut dyn Backend,
+        position: (f32, f32),
+        gridlines: &Gridlines,
+        length: f32,
+    ) {
+        let offsets = self
+            .ticks
+            .iter()
+            .map(|tick| tick.get_tick_offset())
+            .collect::<Vec<f32>>();
+
+        backend.begin_group(
+            &GroupOptions::new()
+                .set_class("gridlines")
+                .set_translate(position.0, position.1),
+        );
+        gridlines.draw(backend, &offsets, length, self.position);
+        backend.end_group();
+    }
+
+    /// Draw the axis on the given backend, with its origin at the given position.
+    pub fn draw(&self, backend: &mut dyn Backend, position: (f32, f32)) -> Result<(), ChartError> {
         let axis_class = match self.position {
             AxisPosition::Top => "x-axis",
             AxisPosition::Bottom => "x-axis",
@@ -203,12 +280,39 @@ impl Axis {
             AxisPosition::Right => "y-axis",
         };
 
-        let mut group = Group::new()
-            .set("class", axis_class)
-            .add(self.axis_line.to_svg().unwrap());
+        backend.begin_group(
+            &GroupOptions::new()
+                .set_class(axis_class)
+                .set_translate(position.0, position.1),
+        );
+        self.axis_line.draw(backend);
 
         for tick in self.ticks.iter() {
-            group.append(tick.to_svg().unwrap());
+            tick.draw(backend)?;
+        }
+
+        for axis_break in self.breaks.iter() {
+            axis_break.draw(backend)?;
+        }
+
+        let group_label_offset = self.tick_group_label_offset();
+        let separator_length = group_label_offset + self.tick_label_font_size.unwrap_or(12) / 2;
+        let mut previous_end: Option<f32> = None;
+        for (label, start, end) in self.tick_groups.iter() {
+            let mut tick_group = AxisTickGroup::new(
+                *start,
+                *end,
+                group_label_offset,
+                label.clone(),
+                self.tick_label_font_size,
+                self.position,
+            );
+            // Separate the group from the previous one in the middle of the space between them.
+            if let Some(previous_end) = previous_end {
+                tick_group.set_separator((previous_end + start) / 2_f32, separator_length);
+            }
+            tick_group.draw(backend)?;
+            previous_end = Some(*end);
         }
 
         if !self.label.is_empty() {
@@ -228,19 +332,22 @@ impl Axis {
                     ((self.length as i32 / 2), -self.calculate_y_for_label(), 90)
                 }
             };
-            let axis_label = Text::new()
-                .set("x", x)
-                .set("y", y)
-                .set("text-anchor", "middle")
-                .set("font-size", self.label_font_size.clone())
-                .set("font-family", "sans-serif")
-                .set("fill", "#777")
-                .set("transform", format!("rotate({})", rotate))
-                .add(TextNode::new(&self.label));
-            group.append(axis_label);
+            // The label is laid out in the rotated coordinate system of the axis,
+            // so its position is rotated back into the axis group.
+            let angle = (rotate as f32).to_radians();
+            let (x, y) = (x as f32, y as f32);
+            let label_x = (x * angle.cos() - y * angle.sin()).round();
+            let label_y = (x * angle.sin() + y * angle.cos()).round();
+            let label_style = TextStyle::new()
+                .set_anchor(TextAnchor::Middle)
+                .set_rotation(rotate as f32)
+                .set_font_size(&self.label_font_size);
+            backend.draw_text(label_x, label_y, &self.label, &label_style);
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 
     /// Generate ticks for the axis based on the scale and position.
@@ -255,29 +362,21 @@ impl Axis {
         };
 
         for tick in scale.get_ticks() {
-            let tick_offset = match position {
-                AxisPosition::Bottom if scale.get_type() == ScaleType::Band => {
-                    scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32
-                }
-                AxisPosition::Bottom => scale.scale(&tick),
-                AxisPosition::Left if scale.get_type() == ScaleType::Band => {
-                    scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32
-                }
-                AxisPosition::Left => scale.scale(&tick),
-                AxisPosition::Top if scale.get_type() == ScaleType::Band => {
-                    scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32
-                }
-                AxisPosition::Top => scale.scale(&tick),
-                AxisPosition::Right if scale.get_type() == ScaleType::Band => {
-                    scale.scale(&tick) + scale.bandwidth().unwrap() / 2_f32
-                }
-                AxisPosition::Right => scale.scale(&tick),
+            // Ticks come from the scale itself, so one that cannot be scaled is not drawn.
+            let scaled_tick = match scale.scale(&tick) {
+                Ok(offset) => offset,
+                Err(_) => continue,
+            };
+            let tick_offset = if scale.get_type() == ScaleType::Band {
+                scaled_tick + scale.bandwidth().unwrap() / 2_f32
+            } else {
+                scaled_tick
             };
             let axis_tick = AxisTick::new(
                 tick_offset,
                 label_offset,
                 0,
-                tick.to_string(),
+                Self::tick_label(scale, &tick),
                 None,
                 position,
             );
@@ -287,6 +386,30 @@ impl Axis {
         ticks
     }
 
+    /// Generate the markers of the breaks of the scale.
+    fn generate_breaks<T: ToString>(
+        scale: &dyn Scale<T>,
+        position: AxisPosition,
+    ) -> Vec<AxisBreak> {
+        scale
+            .range_breaks()
+            .into_iter()
+            .map(|offset| AxisBreak::new(offset, position))
+            .collect()
+    }
+
+    /// Generate the tick groups of the scale, ordered along the axis.
+    fn generate_tick_groups<T: ToString>(scale: &dyn Scale<T>) -> Vec<(String, f32, f32)> {
+        let mut tick_groups = scale.tick_groups();
+        tick_groups.sort_by(|(_, a, _), (_, b, _)| a.total_cmp(b));
+        tick_groups
+    }
+
+    /// Return the label of a tick, as formatted by the scale when it formats its own labels.
+    fn tick_label<T: ToString>(scale: &dyn Scale<T>, tick: &T) -> String {
+        scale.tick_label(tick).unwrap_or_else(|| tick.to_string())
+    }
+
     /// Generate the line that represents the axis.
     fn get_axis_line(position: AxisPosition, chart: &Chart<'_>) -> AxisLine {
         match position {
@@ -322,4 +445,21 @@ mod tests {
 
         assert_eq!(px, 80);
     }
+
+    #[test]
+    fn draws_a_marker_at_each_break() {
+        let scale = crate::ScaleLinear::new()
+            .set_domain(vec![0_f32, 1000_f32])
+            .set_range(vec![0, 140])
+            .set_breaks(vec![(120_f32, 980_f32)]);
+        let svg_string = Chart::new()
+            .add_axis_bottom(&scale)
+            .to_svg_string()
+            .unwrap();
+
+        assert_eq!(svg_string.matches("class=\"axis-break\"").count(), 1);
+        assert!(svg_string.contains("x1=\"114\" x2=\"118\" y1=\"5\" y2=\"-5\""));
+        assert!(svg_string.contains("x1=\"122\" x2=\"126\" y1=\"5\" y2=\"-5\""));
+        assert!(svg_string.contains("x1=\"0\" x2=\"117\" y1=\"0\" y2=\"0\""));
+    }
 }
@@ -0,0 +1,269 @@
+pub(crate) mod svg;
+pub(crate) mod terminal;
+
+/// The horizontal alignment of a text relative to its position.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TextAnchor {
+    Start,
+    Middle,
+    End,
+}
+
+impl TextAnchor {
+    /// Return the name of the anchor as used by the `text-anchor` SVG attribute.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextAnchor::Start => "start",
+            TextAnchor::Middle => "middle",
+            TextAnchor::End => "end",
+        }
+    }
+}
+
+/// The options of a group of drawing primitives.
+#[derive(Debug, Clone, Default)]
+pub struct GroupOptions {
+    class: Option<String>,
+    translate: Option<(f32, f32)>,
+    data_attributes: Vec<(String, String)>,
+}
+
+impl GroupOptions {
+    /// Create group options without a class or a transform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the class that identifies the group, e.g. `bar` or `x-axis`.
+    pub fn set_class(mut self, class: &str) -> Self {
+        self.class = Some(class.to_owned());
+        self
+    }
+
+    /// Move the origin of the group to the given position of its parent.
+    pub fn set_translate(mut self, x: f32, y: f32) -> Self {
+        self.translate = Some((x, y));
+        self
+    }
+
+    /// Attach a named value to the group, e.g. the datum it represents.
+    /// Backends that support it expose the value to interactive outputs.
+    pub fn add_data_attribute(mut self, name: &str, value: &str) -> Self {
+        self.data_attributes
+            .push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Return the class of the group.
+    pub fn get_class(&self) -> Option<&str> {
+        self.class.as_deref()
+    }
+
+    /// Return the offset of the group from the origin of its parent.
+    pub fn get_translate(&self) -> Option<(f32, f32)> {
+        self.translate
+    }
+
+    /// Return the named values attached to the group.
+    pub fn get_data_attributes(&self) -> &[(String, String)] {
+        &self.data_attributes
+    }
+}
+
+/// The fill and stroke of a shape.
+/// Colors are CSS color strings, e.g. `#1f77b4`.
+#[derive(Debug, Clone, Default)]
+pub struct ShapeStyle {
+    fill: Option<String>,
+    stroke: Option<String>,
+    stroke_width: Option<f32>,
+    stroke_dasharray: Option<String>,
+    opacity: Option<f32>,
+    crisp_edges: bool,
+}
+
+impl ShapeStyle {
+    /// Create a style that neither fills nor strokes the shape.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the color the shape is filled with.
+    pub fn set_fill(mut self, color: &str) -> Self {
+        self.fill = Some(color.to_owned());
+        self
+    }
+
+    /// Set the color of the shape outline.
+    pub fn set_stroke(mut self, color: &str) -> Self {
+        self.stroke = Some(color.to_owned());
+        self
+    }
+
+    /// Set the width of the shape outline.
+    pub fn set_stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = Some(width);
+        self
+    }
+
+    /// Set the dash pattern of the shape outline, e.g. `5, 5`.
+    pub fn set_stroke_dasharray(mut self, dasharray: &str) -> Self {
+        self.stroke_dasharray = Some(dasharray.to_owned());
+        self
+    }
+
+    /// Set the opacity of the shape, between 0 (transparent) and 1 (opaque).
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = Some(opacity);
+        self
+    }
+
+    /// Ask the backend to align the shape edges to the pixel grid.
+    pub fn set_crisp_edges(mut self, crisp_edges: bool) -> Self {
+        self.crisp_edges = crisp_edges;
+        self
+    }
+
+    /// Return the fill color of the shape.
+    pub fn get_fill(&self) -> Option<&str> {
+        self.fill.as_deref()
+    }
+
+    /// Return the color of the shape outline.
+    pub fn get_stroke(&self) -> Option<&str> {
+        self.stroke.as_deref()
+    }
+
+    /// Return the width of the shape outline.
+    pub fn get_stroke_width(&self) -> Option<f32> {
+        self.stroke_width
+    }
+
+    /// Return the dash pattern of the shape outline.
+    pub fn get_stroke_dasharray(&self) -> Option<&str> {
+        self.stroke_dasharray.as_deref()
+    }
+
+    /// Return the opacity of the shape.
+    pub fn get_opacity(&self) -> Option<f32> {
+        self.opacity
+    }
+
+    /// Return whether the shape edges should be aligned to the pixel grid.
+    pub fn is_crisp_edges(&self) -> bool {
+        self.crisp_edges
+    }
+}
+
+/// The appearance of a text.
+#[derive(Debug, Clone)]
+pub struct TextStyle {
+    anchor: TextAnchor,
+    font_size: String,
+    fill: String,
+    rotation: f32,
+    vertically_centered: bool,
+}
+
+impl TextStyle {
+    /// Create a style for a start-anchored, 12px, gray text.
+    pub fn new() -> Self {
+        Self {
+            anchor: TextAnchor::Start,
+            font_size: "12px".to_owned(),
+            fill: "#777".to_owned(),
+            rotation: 0_f32,
+            vertically_centered: false,
+        }
+    }
+
+    /// Set the horizontal alignment of the text relative to its position.
+    pub fn set_anchor(mut self, anchor: TextAnchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Set the font size as a CSS length, e.g. `14px`.
+    pub fn set_font_size(mut self, font_size: &str) -> Self {
+        self.font_size = font_size.to_owned();
+        self
+    }
+
+    /// Set the color of the text.
+    pub fn set_fill(mut self, color: &str) -> Self {
+        self.fill = color.to_owned();
+        self
+    }
+
+    /// Set the rotation of the text in degrees around its position.
+    pub fn set_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Center the text vertically on its position instead of sitting on the baseline.
+    pub fn set_vertically_centered(mut self, centered: bool) -> Self {
+        self.vertically_centered = centered;
+        self
+    }
+
+    /// Return the horizontal alignment of the text.
+    pub fn get_anchor(&self) -> TextAnchor {
+        self.anchor
+    }
+
+    /// Return the font size of the text.
+    pub fn get_font_size(&self) -> &str {
+        &self.font_size
+    }
+
+    /// Return the color of the text.
+    pub fn get_fill(&self) -> &str {
+        &self.fill
+    }
+
+    /// Return the rotation of the text in degrees.
+    pub fn get_rotation(&self) -> f32 {
+        self.rotation
+    }
+
+    /// Return whether the text is centered vertically on its position.
+    pub fn is_vertically_centered(&self) -> bool {
+        self.vertically_centered
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A trait for the surfaces a chart can be drawn on.
+/// Chart components lay themselves out and describe what to draw through
+/// these primitives, so each output format only has to implement them.
+/// Coordinates are in chart units, with the origin in the top left corner
+/// of the current group and the y axis pointing down.
+pub trait Backend {
+    /// Open a group, the primitives drawn until the matching `end_group`
+    /// are placed relative to the group origin.
+    fn begin_group(&mut self, options: &GroupOptions);
+
+    /// Close the most recently opened group.
+    fn end_group(&mut self);
+
+    /// Draw a straight line between two points.
+    fn draw_line(&mut self, from: (f32, f32), to: (f32, f32), style: &ShapeStyle);
+
+    /// Draw a rectangle given its top left corner and its size.
+    fn draw_rect(&mut self, x: f32, y: f32, width: f32, height: f32, style: &ShapeStyle);
+
+    /// Draw a circle given its center and radius.
+    fn draw_circle(&mut self, cx: f32, cy: f32, r: f32, style: &ShapeStyle);
+
+    /// Draw a path through the given points, optionally closing it.
+    fn draw_path(&mut self, points: &[(f32, f32)], closed: bool, style: &ShapeStyle);
+
+    /// Draw a text at the given position.
+    fn draw_text(&mut self, x: f32, y: f32, text: &str, style: &TextStyle);
+}
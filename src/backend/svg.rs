@@ -0,0 +1,174 @@
+use crate::backend::{Backend, GroupOptions, ShapeStyle, TextStyle};
+use svg::node::element::path::Data;
+use svg::node::element::{Circle, Group, Line, Path, Rectangle, Text};
+use svg::node::Text as TextNode;
+use svg::Node;
+
+/// A backend that builds the chart as a tree of SVG elements.
+#[derive(Default)]
+pub struct SvgBackend {
+    open_groups: Vec<Group>,
+    closed_groups: Vec<Group>,
+}
+
+impl SvgBackend {
+    /// Create a new SVG backend without any elements.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the drawn elements.
+    /// A single top level group is returned as is, several ones are wrapped in a new group.
+    pub fn into_group(mut self) -> Group {
+        while !self.open_groups.is_empty() {
+            self.end_group();
+        }
+
+        if self.closed_groups.len() == 1 {
+            return self.closed_groups.remove(0);
+        }
+
+        let mut group = Group::new();
+        for child in self.closed_groups {
+            group.append(child);
+        }
+        group
+    }
+
+    /// Add an element to the innermost open group.
+    fn append<N: Node>(&mut self, node: N) {
+        match self.open_groups.last_mut() {
+            Some(group) => group.append(node),
+            None => self.closed_groups.push(Group::new().add(node)),
+        }
+    }
+
+    /// Set the fill and stroke attributes of a shape.
+    fn apply_style<N: Node>(mut node: N, style: &ShapeStyle) -> N {
+        node.assign("fill", style.get_fill().unwrap_or("none"));
+        if let Some(stroke) = style.get_stroke() {
+            node.assign("stroke", stroke);
+        }
+        if let Some(width) = style.get_stroke_width() {
+            node.assign("stroke-width", width);
+        }
+        if let Some(dasharray) = style.get_stroke_dasharray() {
+            node.assign("stroke-dasharray", dasharray);
+        }
+        if let Some(opacity) = style.get_opacity() {
+            node.assign("opacity", opacity);
+        }
+        if style.is_crisp_edges() {
+            node.assign("shape-rendering", "crispEdges");
+        }
+        node
+    }
+}
+
+impl Backend for SvgBackend {
+    fn begin_group(&mut self, options: &GroupOptions) {
+        let mut group = Group::new();
+        if let Some(class) = options.get_class() {
+            group.assign("class", class);
+        }
+        if let Some((x, y)) = options.get_translate() {
+            group.assign("transform", format!("translate({},{})", x, y));
+        }
+        for (name, value) in options.get_data_attributes() {
+            group.assign(format!("data-{}", name), value.as_str());
+        }
+        self.open_groups.push(group);
+    }
+
+    fn end_group(&mut self) {
+        if let Some(group) = self.open_groups.pop() {
+            match self.open_groups.last_mut() {
+                Some(parent) => parent.append(group),
+                None => self.closed_groups.push(group),
+            }
+        }
+    }
+
+    fn draw_line(&mut self, from: (f32, f32), to: (f32, f32), style: &ShapeStyle) {
+        let line = Line::new()
+            .set("x1", from.0)
+            .set("y1", from.1)
+            .set("x2", to.0)
+            .set("y2", to.1);
+        self.append(Self::apply_style(line, style));
+    }
+
+    fn draw_rect(&mut self, x: f32, y: f32, width: f32, height: f32, style: &ShapeStyle) {
+        let rect = Rectangle::new()
+            .set("x", x)
+            .set("y", y)
+            .set("width", width)
+            .set("height", height);
+        self.append(Self::apply_style(rect, style));
+    }
+
+    fn draw_circle(&mut self, cx: f32, cy: f32, r: f32, style: &ShapeStyle) {
+        let circle = Circle::new().set("cx", cx).set("cy", cy).set("r", r);
+        self.append(Self::apply_style(circle, style));
+    }
+
+    fn draw_path(&mut self, points: &[(f32, f32)], closed: bool, style: &ShapeStyle) {
+        let mut data = Data::new();
+        for (i, point) in points.iter().enumerate() {
+            if i == 0 {
+                data = data.move_to(*point);
+            } else {
+                data = data.line_to(*point);
+            }
+        }
+        if closed {
+            data = data.close();
+        }
+        self.append(Self::apply_style(Path::new().set("d", data), style));
+    }
+
+    fn draw_text(&mut self, x: f32, y: f32, text: &str, style: &TextStyle) {
+        let mut label = Text::new()
+            .set("x", x)
+            .set("y", y)
+            .set("text-anchor", style.get_anchor().as_str())
+            .set("font-size", style.get_font_size())
+            .set("font-family", "sans-serif")
+            .set("fill", style.get_fill())
+            .add(TextNode::new(text));
+        if style.is_vertically_centered() {
+            label.assign("dy", ".35em");
+        }
+        if style.get_rotation() != 0_f32 {
+            label.assign(
+                "transform",
+                format!("rotate({},{},{})", style.get_rotation(), x, y),
+            );
+        }
+        self.append(label);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_groups_are_closed_in_order() {
+        let mut backend = SvgBackend::new();
+        backend.begin_group(&GroupOptions::new().set_class("outer"));
+        backend.begin_group(
+            &GroupOptions::new()
+                .set_class("inner")
+                .set_translate(1_f32, 2_f32),
+        );
+        backend.draw_circle(0_f32, 0_f32, 3_f32, &ShapeStyle::new().set_fill("#ff0000"));
+        backend.end_group();
+        backend.end_group();
+
+        let svg = backend.into_group().to_string();
+        assert!(svg.starts_with("<g class=\"outer\">"));
+        assert!(svg.contains("<g class=\"inner\" transform=\"translate(1,2)\">"));
+        assert!(svg.contains("<circle cx=\"0\" cy=\"0\" fill=\"#ff0000\" r=\"3\"/>"));
+    }
+}
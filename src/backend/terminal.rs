@@ -0,0 +1,369 @@
+use crate::backend::{Backend, GroupOptions, ShapeStyle, TextAnchor, TextStyle};
+use crate::error::ChartError;
+
+/// The bit of a braille character that represents each dot of its 2x4 grid,
+/// indexed by `[row][column]`.
+const BRAILLE_DOTS: [[u8; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+/// A single character cell of the terminal canvas.
+#[derive(Copy, Clone, Default)]
+struct Cell {
+    dots: u8,
+    text: Option<char>,
+    color: Option<(u8, u8, u8)>,
+}
+
+/// A backend that draws the chart as Unicode braille characters, so it can be
+/// printed in a terminal. Every character cell holds a 2x4 grid of dots and
+/// takes the color of the last shape drawn into it.
+pub struct TerminalBackend {
+    columns: usize,
+    rows: usize,
+    dot_columns: usize,
+    dot_rows: usize,
+    x_scale: f32,
+    y_scale: f32,
+    cells: Vec<Cell>,
+    offsets: Vec<(f32, f32)>,
+    colors: bool,
+}
+
+impl TerminalBackend {
+    /// Create a canvas `columns` characters wide for a chart of the given size.
+    /// The number of rows is chosen to keep the chart proportions, assuming
+    /// terminal characters are twice as tall as they are wide.
+    /// The chart width and height must be finite and positive.
+    pub fn new(width: f32, height: f32, columns: usize) -> Result<Self, ChartError> {
+        if !(width.is_finite() && width > 0_f32 && height.is_finite() && height > 0_f32) {
+            return Err(ChartError::Layout(format!(
+                "Cannot draw a chart of size {}x{} on a terminal, the size must be positive.",
+                width, height
+            )));
+        }
+        let columns = columns.max(1);
+        let rows = ((columns as f32 * height / width / 2_f32).round() as usize).max(1);
+        let too_large = || {
+            ChartError::Layout(format!(
+                "A terminal canvas of {} columns and {} rows is too large.",
+                columns, rows
+            ))
+        };
+        let cell_count = columns.checked_mul(rows).ok_or_else(too_large)?;
+        let dot_columns = columns.checked_mul(2).ok_or_else(too_large)?;
+        let dot_rows = rows.checked_mul(4).ok_or_else(too_large)?;
+        let mut cells = Vec::new();
+        cells
+            .try_reserve_exact(cell_count)
+            .map_err(|_| too_large())?;
+        cells.resize(cell_count, Cell::default());
+
+        Ok(Self {
+            columns,
+            rows,
+            dot_columns,
+            dot_rows,
+            x_scale: dot_columns as f32 / width,
+            y_scale: dot_rows as f32 / height,
+            cells,
+            offsets: vec![(0_f32, 0_f32)],
+            colors: true,
+        })
+    }
+
+    /// Set whether the output should be colored with ANSI escape codes.
+    pub fn set_colors(mut self, colors: bool) -> Self {
+        self.colors = colors;
+        self
+    }
+
+    /// Return the number of character rows of the canvas.
+    pub fn get_rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Return the canvas as lines of text.
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+
+        for row in self.cells.chunks(self.columns) {
+            let mut current_color = None;
+            for cell in row.iter() {
+                let character = match cell.text {
+                    Some(character) => character,
+                    None if cell.dots == 0 => ' ',
+                    None => std::char::from_u32(0x2800 + cell.dots as u32).unwrap_or(' '),
+                };
+                if self.colors && character != ' ' && cell.color != current_color {
+                    match cell.color {
+                        Some((r, g, b)) => {
+                            output.push_str(&format!("\x1b[38;2;{};{};{}m", r, g, b))
+                        }
+                        None => output.push_str("\x1b[39m"),
+                    }
+                    current_color = cell.color;
+                }
+                output.push(character);
+            }
+            if self.colors && current_color.is_some() {
+                output.push_str("\x1b[0m");
+            }
+            output.push('\n');
+        }
+
+        output
+    }
+
+    /// Convert a position of the current group into dot coordinates.
+    fn to_dots(&self, x: f32, y: f32) -> (f32, f32) {
+        let (offset_x, offset_y) = self.offsets.last().copied().unwrap_or((0_f32, 0_f32));
+        ((x + offset_x) * self.x_scale, (y + offset_y) * self.y_scale)
+    }
+
+    fn set_dot(&mut self, x: isize, y: isize, color: Option<(u8, u8, u8)>) {
+        if x < 0 || y < 0 || x as usize >= self.dot_columns || y as usize >= self.dot_rows {
+            return;
+        }
+        let (x, y) = (x as usize, y as usize);
+        let cell = &mut self.cells[(y / 4) * self.columns + x / 2];
+        cell.dots |= BRAILLE_DOTS[y % 4][x % 2];
+        cell.color = color;
+    }
+
+    /// Draw a line between two dots with Bresenham's algorithm.
+    fn draw_dot_line(&mut self, from: (f32, f32), to: (f32, f32), color: Option<(u8, u8, u8)>) {
+        let (mut x, mut y) = (from.0.round() as isize, from.1.round() as isize);
+        let (x_end, y_end) = (to.0.round() as isize, to.1.round() as isize);
+        let dx = (x_end - x).abs();
+        let dy = -(y_end - y).abs();
+        let step_x = if x < x_end { 1 } else { -1 };
+        let step_y = if y < y_end { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        loop {
+            self.set_dot(x, y, color);
+            if x == x_end && y == y_end {
+                break;
+            }
+            let double_error = 2 * error;
+            if double_error >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if double_error <= dx {
+                error += dx;
+                y += step_y;
+            }
+        }
+    }
+
+    /// Fill a polygon given in dot coordinates with the even-odd rule.
+    fn fill_dot_polygon(&mut self, points: &[(f32, f32)], color: Option<(u8, u8, u8)>) {
+        let min_y = points
+            .iter()
+            .map(|p| p.1)
+            .fold(f32::INFINITY, f32::min)
+            .floor() as isize;
+        let max_y = points
+            .iter()
+            .map(|p| p.1)
+            .fold(f32::NEG_INFINITY, f32::max)
+            .ceil() as isize;
+
+        for y in min_y..=max_y {
+            let scanline = y as f32 + 0.5_f32;
+            let mut crossings = Vec::new();
+            for (i, start) in points.iter().enumerate() {
+                let end = points[(i + 1) % points.len()];
+                if (start.1 <= scanline) != (end.1 <= scanline) {
+                    let t = (scanline - start.1) / (end.1 - start.1);
+                    crossings.push(start.0 + t * (end.0 - start.0));
+                }
+            }
+            crossings.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            for pair in crossings.chunks(2) {
+                if let [start, end] = pair {
+                    for x in start.round() as isize..end.round() as isize {
+                        self.set_dot(x, y, color);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Draw the outline and the fill of a polygon given in the current group coordinates.
+    fn draw_shape(&mut self, points: &[(f32, f32)], closed: bool, style: &ShapeStyle) {
+        let points = points
+            .iter()
+            .map(|point| self.to_dots(point.0, point.1))
+            .collect::<Vec<(f32, f32)>>();
+
+        if let Some(fill) = style.get_fill() {
+            if closed && fill != "none" {
+                self.fill_dot_polygon(&points, parse_color(fill));
+            }
+        }
+        if let Some(stroke) = style.get_stroke() {
+            if stroke != "none" {
+                let color = parse_color(stroke);
+                for segment in points.windows(2) {
+                    self.draw_dot_line(segment[0], segment[1], color);
+                }
+                if closed && points.len() > 2 {
+                    self.draw_dot_line(points[points.len() - 1], points[0], color);
+                }
+            }
+        }
+    }
+}
+
+impl Backend for TerminalBackend {
+    fn begin_group(&mut self, options: &GroupOptions) {
+        let (x, y) = self.offsets.last().copied().unwrap_or((0_f32, 0_f32));
+        let (translate_x, translate_y) = options.get_translate().unwrap_or((0_f32, 0_f32));
+        self.offsets.push((x + translate_x, y + translate_y));
+    }
+
+    fn end_group(&mut self) {
+        if self.offsets.len() > 1 {
+            self.offsets.pop();
+        }
+    }
+
+    fn draw_line(&mut self, from: (f32, f32), to: (f32, f32), style: &ShapeStyle) {
+        self.draw_shape(&[from, to], false, style);
+    }
+
+    fn draw_rect(&mut self, x: f32, y: f32, width: f32, height: f32, style: &ShapeStyle) {
+        let corners = [
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ];
+        self.draw_shape(&corners, true, style);
+    }
+
+    fn draw_circle(&mut self, cx: f32, cy: f32, r: f32, style: &ShapeStyle) {
+        let points = (0..16)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::PI / 8_f32;
+                (cx + r * angle.cos(), cy + r * angle.sin())
+            })
+            .collect::<Vec<(f32, f32)>>();
+        let (dot_x, dot_y) = self.to_dots(cx, cy);
+        let color = style
+            .get_fill()
+            .or_else(|| style.get_stroke())
+            .and_then(parse_color);
+
+        // Small markers would disappear when filled, so the center is always drawn.
+        self.set_dot(dot_x.round() as isize, dot_y.round() as isize, color);
+        self.draw_shape(&points, true, style);
+    }
+
+    fn draw_path(&mut self, points: &[(f32, f32)], closed: bool, style: &ShapeStyle) {
+        self.draw_shape(points, closed, style);
+    }
+
+    fn draw_text(&mut self, x: f32, y: f32, text: &str, style: &TextStyle) {
+        let (dot_x, dot_y) = self.to_dots(x, y);
+        let column = (dot_x / 2_f32).floor() as isize;
+        let row = if style.is_vertically_centered() {
+            (dot_y / 4_f32).floor() as isize
+        } else {
+            // The position is the baseline, which is at the bottom of the text.
+            (dot_y / 4_f32 - 0.5_f32).floor() as isize
+        };
+        let length = text.chars().count() as isize;
+        let start = match style.get_anchor() {
+            TextAnchor::Start => 0,
+            TextAnchor::Middle => -length / 2,
+            TextAnchor::End => -length,
+        };
+        let vertical = (style.get_rotation().abs() - 90_f32).abs() < 45_f32;
+        let color = parse_color(style.get_fill());
+
+        for (i, character) in text.chars().enumerate() {
+            let (column, row) = if vertical {
+                (column, row + start + i as isize)
+            } else {
+                (column + start + i as isize, row)
+            };
+            if column < 0 || row < 0 || column as usize >= self.columns || row as usize >= self.rows
+            {
+                continue;
+            }
+            let cell = &mut self.cells[row as usize * self.columns + column as usize];
+            cell.text = Some(character);
+            cell.color = color;
+        }
+    }
+}
+
+/// Parse a `#rgb` or `#rrggbb` color.
+fn parse_color(color: &str) -> Option<(u8, u8, u8)> {
+    let hex = color.strip_prefix('#')?;
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()?;
+
+    match digits.as_slice() {
+        [r, g, b] => Some((r * 17, g * 17, b * 17)),
+        [r1, r2, g1, g2, b1, b2] => Some((r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_braille_lines_and_text() {
+        let mut backend = TerminalBackend::new(40_f32, 20_f32, 20)
+            .unwrap()
+            .set_colors(false);
+        backend.draw_line(
+            (0_f32, 0_f32),
+            (40_f32, 0_f32),
+            &ShapeStyle::new().set_stroke("#000"),
+        );
+        backend.draw_text(
+            0_f32,
+            10_f32,
+            "42",
+            &TextStyle::new().set_vertically_centered(true),
+        );
+        let output = backend.render();
+        let lines = output.lines().collect::<Vec<&str>>();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "⠉".repeat(20));
+        assert!(lines[2].starts_with("42"));
+    }
+
+    #[test]
+    fn parses_short_and_long_hex_colors() {
+        assert_eq!(parse_color("#777"), Some((119, 119, 119)));
+        assert_eq!(parse_color("#1f77b4"), Some((31, 119, 180)));
+        assert_eq!(parse_color("none"), None);
+    }
+
+    #[test]
+    fn rejects_empty_chart_size() {
+        assert!(matches!(
+            TerminalBackend::new(0_f32, 20_f32, 80),
+            Err(ChartError::Layout(_))
+        ));
+        assert!(matches!(
+            TerminalBackend::new(40_f32, f32::NAN, 80),
+            Err(ChartError::Layout(_))
+        ));
+        // A single row of that many columns has more dots than fit in a usize.
+        assert!(matches!(
+            TerminalBackend::new(1_f32, 1e-30_f32, usize::MAX / 2 + 1),
+            Err(ChartError::Layout(_))
+        ));
+    }
+}
@@ -0,0 +1,445 @@
+use charts::{
+    AxisPosition, AxisSpec, BarViewSpec, ChartError, ChartSpec, DataSpec, DatumSpec, MarginsSpec,
+    PointViewSpec, ScaleSpec, ValueSpec, ViewSpec,
+};
+use clap::{Parser, ValueEnum};
+use std::io::{self, Read, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Render a chart from the columns of a CSV file.
+/// The scale domains are inferred from the data.
+#[derive(Debug, Parser)]
+#[command(name = "charts", version)]
+struct Args {
+    /// The CSV file to read, with a header row. Reads from stdin when omitted or `-`.
+    input: Option<PathBuf>,
+
+    /// The type of chart to render.
+    #[arg(short = 't', long = "type", value_enum, default_value = "bar")]
+    chart_type: ChartType,
+
+    /// The column with the x values, or the categories of a bar chart.
+    #[arg(short, long)]
+    x: String,
+
+    /// The column with the y values.
+    #[arg(short, long)]
+    y: String,
+
+    /// The column that splits the rows into series, or into the stacks of a stacked bar chart.
+    #[arg(short, long)]
+    key: Option<String>,
+
+    /// The file to save the chart to, its extension picks the format.
+    /// The SVG is written to stdout when omitted.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+
+    /// The title of the chart.
+    #[arg(long)]
+    title: Option<String>,
+
+    /// The width of the chart.
+    #[arg(long)]
+    width: Option<isize>,
+
+    /// The height of the chart.
+    #[arg(long)]
+    height: Option<isize>,
+
+    /// The top, right, bottom and left margins of the chart, e.g. `180,10,50,60`.
+    #[arg(long, value_delimiter = ',')]
+    margins: Option<Vec<isize>>,
+
+    /// The label of the bottom axis.
+    #[arg(long)]
+    x_label: Option<String>,
+
+    /// The label of the left axis.
+    #[arg(long)]
+    y_label: Option<String>,
+
+    /// The format of the bottom axis tick labels, e.g. `.2s`.
+    #[arg(long)]
+    x_tick_format: Option<String>,
+
+    /// The format of the left axis tick labels, e.g. `.2s`.
+    #[arg(long)]
+    y_tick_format: Option<String>,
+
+    /// The rotation of the bottom axis tick labels in degrees.
+    #[arg(long, allow_negative_numbers = true)]
+    x_tick_rotation: Option<isize>,
+
+    /// The side of the chart to show the legend at.
+    #[arg(long, value_enum)]
+    legend: Option<LegendPosition>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum)]
+enum ChartType {
+    Bar,
+    StackedBar,
+    Line,
+    Scatter,
+    Area,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum LegendPosition {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl From<LegendPosition> for AxisPosition {
+    fn from(position: LegendPosition) -> Self {
+        match position {
+            LegendPosition::Top => AxisPosition::Top,
+            LegendPosition::Right => AxisPosition::Right,
+            LegendPosition::Bottom => AxisPosition::Bottom,
+            LegendPosition::Left => AxisPosition::Left,
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("charts: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<(), ChartError> {
+    let mut input = String::new();
+    match &args.input {
+        Some(path) if path.as_os_str() != "-" => {
+            input = std::fs::read_to_string(path)?;
+        }
+        _ => {
+            io::stdin().read_to_string(&mut input)?;
+        }
+    }
+
+    let data = read_columns(&input, &args.x, &args.y, args.key.as_deref())?;
+    let spec = build_spec(args, data)?;
+
+    match &args.output {
+        Some(path) => spec.save(path),
+        None => write_stdout(&spec.to_svg_string()?),
+    }
+}
+
+/// Write the SVG to stdout. A reader that stops early, e.g. `head`, closes the pipe,
+/// which ends the output rather than being an error.
+fn write_stdout(svg: &str) -> Result<(), ChartError> {
+    let mut stdout = io::stdout().lock();
+    match writeln!(stdout, "{}", svg).and_then(|()| stdout.flush()) {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => Err(ChartError::Io(error)),
+        _ => Ok(()),
+    }
+}
+
+/// Read the x, y and key columns of a CSV document with a header row.
+fn read_columns(
+    input: &str,
+    x: &str,
+    y: &str,
+    key: Option<&str>,
+) -> Result<Vec<DatumSpec>, ChartError> {
+    let csv_error = |error: csv::Error| ChartError::Data(format!("cannot read the CSV: {}", error));
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    let headers = reader.headers().map_err(csv_error)?.clone();
+    let column = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header.trim() == name)
+            .ok_or_else(|| ChartError::Data(format!("the CSV has no `{}` column", name)))
+    };
+    let x = column(x)?;
+    let y = column(y)?;
+    let key = key.map(column).transpose()?;
+
+    let mut data = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(csv_error)?;
+        let field = |index: usize| record.get(index).unwrap_or_default().trim().to_string();
+        data.push(DatumSpec {
+            x: ValueSpec::Text(field(x)),
+            y: ValueSpec::Text(field(y)),
+            key: key.map(field),
+        });
+    }
+    Ok(data)
+}
+
+/// Describe the chart requested by the arguments, inferring the scales from the data.
+fn build_spec(args: &Args, data: Vec<DatumSpec>) -> Result<ChartSpec, ChartError> {
+    if args.chart_type == ChartType::StackedBar && args.key.is_none() {
+        return Err(ChartError::Data(
+            "a stacked bar chart needs a --key column".to_string(),
+        ));
+    }
+    if args.chart_type == ChartType::Area && args.key.is_some() {
+        return Err(ChartError::Data(
+            "an area chart draws a single series, --key is not supported".to_string(),
+        ));
+    }
+
+    let keys = args
+        .key
+        .as_ref()
+        .map(|_| unique(data.iter().filter_map(|datum| datum.key.clone())));
+    let x_scale = match args.chart_type {
+        ChartType::Bar | ChartType::StackedBar => band_scale(&data),
+        _ => infer_scale(&data),
+    };
+    let y_scale = ScaleSpec::Linear {
+        domain: value_domain(&data, args.chart_type)?,
+        nice: Some(true),
+        clamp: None,
+        padding: None,
+        tick_count: None,
+        tick_values: None,
+        breaks: None,
+    };
+
+    let mut spec = ChartSpec::new(x_scale, y_scale);
+    spec.width = args.width;
+    spec.height = args.height;
+    if let Some(margins) = &args.margins {
+        spec.margins = match margins.as_slice() {
+            [top, right, bottom, left] => Some(MarginsSpec {
+                top: *top,
+                right: *right,
+                bottom: *bottom,
+                left: *left,
+            }),
+            _ => {
+                return Err(ChartError::Layout(
+                    "--margins needs the top, right, bottom and left margins".to_string(),
+                ))
+            }
+        };
+    }
+    spec.title = args.title.clone();
+    spec.legend_position = args.legend.map(AxisPosition::from);
+    spec.axes = vec![
+        AxisSpec {
+            position: AxisPosition::Bottom,
+            label: args.x_label.clone(),
+            label_font_size: None,
+            tick_label_format: args.x_tick_format.clone(),
+            tick_label_rotation: args.x_tick_rotation,
+            tick_label_font_size: None,
+        },
+        AxisSpec {
+            position: AxisPosition::Left,
+            label: args.y_label.clone(),
+            label_font_size: None,
+            tick_label_format: args.y_tick_format.clone(),
+            tick_label_rotation: None,
+            tick_label_font_size: None,
+        },
+    ];
+
+    let data = DataSpec::Inline(data);
+    spec.views = vec![match args.chart_type {
+        ChartType::Bar | ChartType::StackedBar => ViewSpec::VerticalBar(BarViewSpec {
+            data,
+            keys,
+            colors: None,
+            custom_data_label: None,
+            labels_visible: None,
+            label_position: None,
+            label_font_size: None,
+            label_rounding_precision: None,
+            legend_font_size: None,
+        }),
+        chart_type => {
+            let view = PointViewSpec {
+                data,
+                keys,
+                colors: None,
+                custom_data_label: None,
+                labels_visible: Some(false),
+                label_position: None,
+                marker_type: None,
+                legend_font_size: None,
+            };
+            match chart_type {
+                ChartType::Line => ViewSpec::Line(view),
+                ChartType::Scatter => ViewSpec::Scatter(view),
+                _ => ViewSpec::Area(view),
+            }
+        }
+    }];
+
+    Ok(spec)
+}
+
+/// Return a band scale over the x values in the order they first appear.
+fn band_scale(data: &[DatumSpec]) -> ScaleSpec {
+    ScaleSpec::Band {
+        domain: unique(data.iter().map(|datum| text(&datum.x))),
+        inner_padding: None,
+        outer_padding: None,
+    }
+}
+
+/// Return a point scale over the x values in the order they first appear,
+/// with half a step of space before the first and after the last point.
+fn point_scale(data: &[DatumSpec]) -> ScaleSpec {
+    ScaleSpec::Point {
+        domain: unique(data.iter().map(|datum| text(&datum.x))),
+        padding: Some(0.5),
+        align: None,
+    }
+}
+
+/// Return a linear scale over the x values when they are all numbers,
+/// and a point scale otherwise.
+fn infer_scale(data: &[DatumSpec]) -> ScaleSpec {
+    let numbers = data
+        .iter()
+        .map(|datum| number(&datum.x))
+        .collect::<Option<Vec<f32>>>();
+
+    match numbers {
+        Some(numbers) if !numbers.is_empty() => ScaleSpec::Linear {
+            domain: extent(numbers.into_iter(), false),
+            nice: None,
+            clamp: None,
+            padding: None,
+            tick_count: None,
+            tick_values: None,
+            breaks: None,
+        },
+        _ => point_scale(data),
+    }
+}
+
+/// Return the domain of the y values. Bars and areas start at zero, and
+/// stacked bars are measured up to the sum of their blocks.
+fn value_domain(data: &[DatumSpec], chart_type: ChartType) -> Result<Vec<f32>, ChartError> {
+    let values = data
+        .iter()
+        .map(|datum| {
+            number(&datum.y)
+                .ok_or_else(|| ChartError::Data(format!("`{}` is not a number", text(&datum.y))))
+        })
+        .collect::<Result<Vec<f32>, ChartError>>()?;
+
+    Ok(match chart_type {
+        ChartType::Bar | ChartType::StackedBar => {
+            let mut totals: Vec<(String, f32)> = Vec::new();
+            for (datum, value) in data.iter().zip(values) {
+                let category = text(&datum.x);
+                match totals.iter_mut().find(|(name, _)| *name == category) {
+                    Some((_, total)) => *total += value,
+                    None => totals.push((category, value)),
+                }
+            }
+            extent(totals.into_iter().map(|(_, total)| total), true)
+        }
+        ChartType::Area => extent(values.into_iter(), true),
+        ChartType::Line | ChartType::Scatter => extent(values.into_iter(), false),
+    })
+}
+
+/// Return the smallest and largest values, optionally extended to include zero.
+/// An empty or single valued extent is widened so the scale stays usable.
+fn extent(values: impl Iterator<Item = f32>, include_zero: bool) -> Vec<f32> {
+    let (mut min, mut max) = values
+        .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+            (min.min(value), max.max(value))
+        });
+    if include_zero {
+        min = min.min(0_f32);
+        max = max.max(0_f32);
+    }
+    if min > max {
+        return vec![0_f32, 1_f32];
+    }
+    if min == max {
+        return vec![min - 1_f32, max + 1_f32];
+    }
+    vec![min, max]
+}
+
+fn unique(values: impl Iterator<Item = String>) -> Vec<String> {
+    let mut unique: Vec<String> = Vec::new();
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    unique
+}
+
+fn text(value: &ValueSpec) -> String {
+    match value {
+        ValueSpec::Number(number) => number.to_string(),
+        ValueSpec::Text(text) => text.clone(),
+    }
+}
+
+fn number(value: &ValueSpec) -> Option<f32> {
+    match value {
+        ValueSpec::Number(number) => Some(*number),
+        ValueSpec::Text(text) => text.trim().parse().ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CSV: &str = "year,sales,region\n2019,10,EU\n2020,30,EU\n2019,20,US\n2020,25,US\n";
+
+    #[test]
+    fn stacked_bar_domain_covers_category_totals() {
+        let args = Args::parse_from([
+            "charts",
+            "-t",
+            "stacked-bar",
+            "-x",
+            "year",
+            "-y",
+            "sales",
+            "-k",
+            "region",
+        ]);
+        let data = read_columns(CSV, "year", "sales", Some("region")).unwrap();
+        let spec = build_spec(&args, data).unwrap();
+
+        assert!(
+            matches!(&spec.x_scale, ScaleSpec::Band { domain, .. } if domain == &["2019", "2020"])
+        );
+        assert!(
+            matches!(&spec.y_scale, ScaleSpec::Linear { domain, .. } if domain == &[0_f32, 55_f32])
+        );
+        assert!(spec.to_svg_string().is_ok());
+    }
+
+    #[test]
+    fn numeric_x_column_gets_linear_scale() {
+        let args = Args::parse_from(["charts", "-t", "line", "-x", "year", "-y", "sales"]);
+        let data = read_columns(CSV, "year", "sales", None).unwrap();
+        let spec = build_spec(&args, data).unwrap();
+
+        assert!(
+            matches!(&spec.x_scale, ScaleSpec::Linear { domain, .. } if domain == &[2019_f32, 2020_f32])
+        );
+        assert!(
+            matches!(&spec.y_scale, ScaleSpec::Linear { domain, .. } if domain == &[10_f32, 30_f32])
+        );
+    }
+}
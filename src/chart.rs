@@ -1,15 +1,20 @@
 use crate::axis::AxisPosition;
+use crate::backend::svg::SvgBackend;
+use crate::backend::terminal::TerminalBackend;
+use crate::backend::{Backend, GroupOptions, TextAnchor, TextStyle};
 use crate::components::legend::LegendEntry;
+use crate::error::ChartError;
+use crate::export::{html, pdf, png};
 use crate::legend::Legend;
 use crate::views::View;
-use crate::{Axis, Scale};
+use crate::{Axis, Gridlines, Scale};
 use std::ffi::OsStr;
+use std::fs;
+use std::io::Write;
 use std::path::Path;
 use std::string::ToString;
 use svg::node::element::Group;
-use svg::node::element::Text;
-use svg::node::Text as TextNode;
-use svg::Node;
+use svg::Document;
 
 /// Define the orientation enum to aid in rendering and business logic.
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -31,10 +36,15 @@ pub struct Chart<'a> {
     x_axis_bottom: Option<Axis>,
     y_axis_left: Option<Axis>,
     y_axis_right: Option<Axis>,
+    x_axis_top_gridlines: Option<Gridlines>,
+    x_axis_bottom_gridlines: Option<Gridlines>,
+    y_axis_left_gridlines: Option<Gridlines>,
+    y_axis_right_gridlines: Option<Gridlines>,
     legend_position: Option<AxisPosition>,
     views: Vec<&'a dyn View<'a>>,
     title: String,
     title_font_size: String,
+    raster_scale: f32,
 }
 
 impl<'a> Default for Chart<'a> {
@@ -50,10 +60,15 @@ impl<'a> Default for Chart<'a> {
             x_axis_bottom: None,
             y_axis_left: None,
             y_axis_right: None,
+            x_axis_top_gridlines: None,
+            x_axis_bottom_gridlines: None,
+            y_axis_left_gridlines: None,
+            y_axis_right_gridlines: None,
             legend_position: None,
             views: Vec::new(),
             title: String::new(),
             title_font_size: "24px".to_owned(),
+            raster_scale: 1_f32,
         }
     }
 }
@@ -88,6 +103,13 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Set the number of pixels that a unit of the chart occupies when saved as a raster image.
+    /// A scale of 2 will produce a PNG that is twice the chart width and height.
+    pub fn set_raster_scale(mut self, scale: f32) -> Self {
+        self.raster_scale = scale;
+        self
+    }
+
     /// Set the margins of the chart to provided values.
     pub fn set_margins(mut self, top: isize, right: isize, bottom: isize, left: isize) -> Self {
         self.margin_top = top;
@@ -128,83 +150,103 @@ impl<'a> Chart<'a> {
     }
 
     /// Add a label for the right of the chart.
-    pub fn add_right_axis_label<T: ToString>(mut self, label: T) -> Self {
+    pub fn add_right_axis_label<T: ToString>(mut self, label: T) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.y_axis_right {
             axis.set_axis_label(label.to_string())
         } else {
-            panic!("You cannot add a label to right axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot add a label to right axis without adding an axis first.".to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Add a label for the left of the chart.
-    pub fn add_left_axis_label<T: ToString>(mut self, label: T) -> Self {
+    pub fn add_left_axis_label<T: ToString>(mut self, label: T) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.y_axis_left {
             axis.set_axis_label(label.to_string())
         } else {
-            panic!("You cannot add a label to left axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot add a label to left axis without adding an axis first.".to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Add a label for the top of the chart.
-    pub fn add_top_axis_label<T: ToString>(mut self, label: T) -> Self {
+    pub fn add_top_axis_label<T: ToString>(mut self, label: T) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.x_axis_top {
             axis.set_axis_label(label.to_string())
         } else {
-            panic!("You cannot add a label to top axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot add a label to top axis without adding an axis first.".to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Add a label for the bottom of the chart.
-    pub fn add_bottom_axis_label<T: ToString>(mut self, label: T) -> Self {
+    pub fn add_bottom_axis_label<T: ToString>(mut self, label: T) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.x_axis_bottom {
             axis.set_axis_label(label.to_string())
         } else {
-            panic!("You cannot add a label to bottom axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot add a label to bottom axis without adding an axis first.".to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Specify the font size for the label for the right of the chart.
-    pub fn set_right_axis_label_font_size(mut self, size: usize) -> Self {
+    pub fn set_right_axis_label_font_size(mut self, size: usize) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.y_axis_right {
             axis.set_axis_label_font_size(size)
         } else {
-            panic!("You cannot set the font size for the right axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot set the font size for the right axis without adding an axis first."
+                    .to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Specify the font size for the label for the left of the chart.
-    pub fn set_left_axis_label_font_size(mut self, size: usize) -> Self {
+    pub fn set_left_axis_label_font_size(mut self, size: usize) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.y_axis_left {
             axis.set_axis_label_font_size(size)
         } else {
-            panic!("You cannot set the font size for the left axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot set the font size for the left axis without adding an axis first."
+                    .to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Specify the font size for the label for the top of the chart.
-    pub fn set_top_axis_label_font_size(mut self, size: usize) -> Self {
+    pub fn set_top_axis_label_font_size(mut self, size: usize) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.x_axis_top {
             axis.set_axis_label_font_size(size)
         } else {
-            panic!("You cannot set the font size for the top axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot set the font size for the top axis without adding an axis first."
+                    .to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Specify the font size for the label for the bottom of the chart.
-    pub fn set_bottom_axis_label_font_size(mut self, size: usize) -> Self {
+    pub fn set_bottom_axis_label_font_size(mut self, size: usize) -> Result<Self, ChartError> {
         if let Some(ref mut axis) = self.x_axis_bottom {
             axis.set_axis_label_font_size(size)
         } else {
-            panic!("You cannot set the font size for the bottom axis without adding an axis first.")
+            return Err(ChartError::Layout(
+                "You cannot set the font size for the bottom axis without adding an axis first."
+                    .to_string(),
+            ));
         }
-        self
+        Ok(self)
     }
 
     /// Return the offset from the left where the view starts.
@@ -255,188 +297,204 @@ impl<'a> Chart<'a> {
 
     /// Set the tick label font size of the bottom axis tick labels.
     pub fn set_bottom_axis_tick_label_font_size(mut self, size: usize) -> Self {
-        match &mut self.x_axis_bottom {
-            Some(axis) => axis.set_tick_label_font_size(size),
-            None => {}
+        if let Some(axis) = &mut self.x_axis_bottom {
+            axis.set_tick_label_font_size(size);
         }
         self
     }
 
     /// Set the tick label font size of the top axis tick labels.
     pub fn set_top_axis_tick_label_font_size(mut self, size: usize) -> Self {
-        match &mut self.x_axis_top {
-            Some(axis) => axis.set_tick_label_font_size(size),
-            None => {}
+        if let Some(axis) = &mut self.x_axis_top {
+            axis.set_tick_label_font_size(size);
         }
         self
     }
 
     /// Set the tick label font size of the left axis tick labels.
     pub fn set_left_axis_tick_label_font_size(mut self, size: usize) -> Self {
-        match &mut self.y_axis_left {
-            Some(axis) => axis.set_tick_label_font_size(size),
-            None => {}
+        if let Some(axis) = &mut self.y_axis_left {
+            axis.set_tick_label_font_size(size);
         }
         self
     }
 
     /// Set the tick label font size of the top axis tick labels.
     pub fn set_right_axis_tick_label_font_size(mut self, size: usize) -> Self {
-        match &mut self.y_axis_right {
-            Some(axis) => axis.set_tick_label_font_size(size),
-            None => {}
+        if let Some(axis) = &mut self.y_axis_right {
+            axis.set_tick_label_font_size(size);
         }
         self
     }
 
     /// Set the rotation in degrees of the bottom axis tick labels.
     pub fn set_bottom_axis_tick_label_rotation(mut self, rotation: isize) -> Self {
-        match &mut self.x_axis_bottom {
-            Some(axis) => axis.set_tick_label_rotation(rotation),
-            None => {}
+        if let Some(axis) = &mut self.x_axis_bottom {
+            axis.set_tick_label_rotation(rotation);
         }
         self
     }
 
     /// Set the rotation in degrees of the top axis tick labels.
     pub fn set_top_axis_tick_label_rotation(mut self, rotation: isize) -> Self {
-        match &mut self.x_axis_top {
-            Some(axis) => axis.set_tick_label_rotation(rotation),
-            None => {}
+        if let Some(axis) = &mut self.x_axis_top {
+            axis.set_tick_label_rotation(rotation);
         }
         self
     }
 
     /// Set the rotation in degrees of the left axis tick labels.
     pub fn set_left_axis_tick_label_rotation(mut self, rotation: isize) -> Self {
-        match &mut self.y_axis_left {
-            Some(axis) => axis.set_tick_label_rotation(rotation),
-            None => {}
+        if let Some(axis) = &mut self.y_axis_left {
+            axis.set_tick_label_rotation(rotation);
         }
         self
     }
 
     /// Set the rotation in degrees of the right axis tick labels.
     pub fn set_right_axis_tick_label_rotation(mut self, rotation: isize) -> Self {
-        match &mut self.y_axis_right {
-            Some(axis) => axis.set_tick_label_rotation(rotation),
-            None => {}
+        if let Some(axis) = &mut self.y_axis_right {
+            axis.set_tick_label_rotation(rotation);
         }
         self
     }
 
     /// Set the format type of labels on the left axis.
     pub fn set_left_axis_tick_label_format(mut self, format: &str) -> Self {
-        match &mut self.y_axis_left {
-            Some(axis) => axis.set_tick_label_format(format),
-            None => {}
+        if let Some(axis) = &mut self.y_axis_left {
+            axis.set_tick_label_format(format);
         }
         self
     }
 
     /// Set the format type of labels on the right axis.
     pub fn set_right_axis_tick_label_format(mut self, format: &str) -> Self {
-        match &mut self.y_axis_right {
-            Some(axis) => axis.set_tick_label_format(format),
-            None => {}
+        if let Some(axis) = &mut self.y_axis_right {
+            axis.set_tick_label_format(format);
         }
         self
     }
 
     /// Set the format type of labels on the top axis.
     pub fn set_top_axis_tick_label_format(mut self, format: &str) -> Self {
-        match &mut self.x_axis_top {
-            Some(axis) => axis.set_tick_label_format(format),
-            None => {}
+        if let Some(axis) = &mut self.x_axis_top {
+            axis.set_tick_label_format(format);
         }
         self
     }
 
     /// Set the format type of labels on the bottom axis.
     pub fn set_bottom_axis_tick_label_format(mut self, format: &str) -> Self {
-        match &mut self.x_axis_bottom {
-            Some(axis) => axis.set_tick_label_format(format),
-            None => {}
+        if let Some(axis) = &mut self.x_axis_bottom {
+            axis.set_tick_label_format(format);
         }
         self
     }
 
-    /// Generate the SVG for the chart and its components.
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new().set("class", "g-chart");
+    /// Draw gridlines across the plot area at the ticks of the bottom axis.
+    /// They are drawn when the chart has a bottom axis, whether it is added before or after.
+    pub fn set_bottom_axis_gridlines(mut self, gridlines: Gridlines) -> Self {
+        self.x_axis_bottom_gridlines = Some(gridlines);
+        self
+    }
 
-        // Add chart title
-        if !self.title.is_empty() {
-            let title_group = Group::new()
-                .set("class", "g-title")
-                .set("transform", format!("translate({},{})", self.width / 2, 25))
-                .add(
-                    Text::new()
-                        .set("x", 0)
-                        .set("y", 0)
-                        .set("dy", ".35em")
-                        .set("fill", "#777")
-                        .set("text-anchor", "middle")
-                        .set("font-size", self.title_font_size.clone())
-                        .set("font-family", "sans-serif")
-                        .add(TextNode::new(&self.title)),
-                );
-            group.append(title_group);
-        }
+    /// Draw gridlines across the plot area at the ticks of the top axis.
+    /// They are drawn when the chart has a top axis, whether it is added before or after.
+    pub fn set_top_axis_gridlines(mut self, gridlines: Gridlines) -> Self {
+        self.x_axis_top_gridlines = Some(gridlines);
+        self
+    }
 
-        if let Some(ref axis) = self.x_axis_top {
-            let mut axis_group = axis.to_svg().unwrap();
-            axis_group.assign(
-                "transform",
-                format!("translate({},{})", self.margin_left, self.margin_top),
-            );
-            group.append(axis_group);
-        };
+    /// Draw gridlines across the plot area at the ticks of the left axis.
+    /// They are drawn when the chart has a left axis, whether it is added before or after.
+    pub fn set_left_axis_gridlines(mut self, gridlines: Gridlines) -> Self {
+        self.y_axis_left_gridlines = Some(gridlines);
+        self
+    }
 
-        if let Some(ref axis) = self.x_axis_bottom {
-            let mut axis_group = axis.to_svg().unwrap();
-            axis_group.assign(
-                "transform",
-                format!(
-                    "translate({},{})",
-                    self.margin_left,
-                    self.height - self.margin_bottom
-                ),
-            );
-            group.append(axis_group);
-        };
+    /// Draw gridlines across the plot area at the ticks of the right axis.
+    /// They are drawn when the chart has a right axis, whether it is added before or after.
+    pub fn set_right_axis_gridlines(mut self, gridlines: Gridlines) -> Self {
+        self.y_axis_right_gridlines = Some(gridlines);
+        self
+    }
+
+    /// Draw the chart and its components on the given backend.
+    pub fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        backend.begin_group(&GroupOptions::new().set_class("g-chart"));
 
-        if let Some(ref axis) = self.y_axis_left {
-            let mut axis_group = axis.to_svg().unwrap();
-            axis_group.assign(
-                "transform",
-                format!("translate({},{})", self.margin_left, self.margin_top),
+        // Add chart title
+        if !self.title.is_empty() {
+            backend.begin_group(
+                &GroupOptions::new()
+                    .set_class("g-title")
+                    .set_translate((self.width / 2) as f32, 25_f32),
             );
-            group.append(axis_group);
-        };
+            let title_style = TextStyle::new()
+                .set_anchor(TextAnchor::Middle)
+                .set_vertically_centered(true)
+                .set_font_size(&self.title_font_size);
+            backend.draw_text(0_f32, 0_f32, &self.title, &title_style);
+            backend.end_group();
+        }
 
-        if let Some(ref axis) = self.y_axis_right {
-            let mut axis_group = axis.to_svg().unwrap();
-            axis_group.assign(
-                "transform",
-                format!(
-                    "translate({},{})",
-                    self.width - self.margin_right,
-                    self.margin_top
+        // The gridlines of an axis span the plot area across it.
+        let (view_width, view_height) = (self.get_view_width(), self.get_view_height());
+        let axes = [
+            (
+                &self.x_axis_top,
+                (self.margin_left as f32, self.margin_top as f32),
+                &self.x_axis_top_gridlines,
+                view_height,
+            ),
+            (
+                &self.x_axis_bottom,
+                (
+                    self.margin_left as f32,
+                    (self.height - self.margin_bottom) as f32,
                 ),
-            );
-            group.append(axis_group);
-        };
+                &self.x_axis_bottom_gridlines,
+                view_height,
+            ),
+            (
+                &self.y_axis_left,
+                (self.margin_left as f32, self.margin_top as f32),
+                &self.y_axis_left_gridlines,
+                view_width,
+            ),
+            (
+                &self.y_axis_right,
+                (
+                    (self.width - self.margin_right) as f32,
+                    self.margin_top as f32,
+                ),
+                &self.y_axis_right_gridlines,
+                view_width,
+            ),
+        ];
+
+        // Gridlines are drawn first so that the axes and the views are drawn over them.
+        for (axis, position, gridlines, length) in axes.iter() {
+            if let (Some(axis), Some(gridlines)) = (axis, gridlines) {
+                axis.draw_gridlines(backend, *position, gridlines, *length as f32);
+            }
+        }
 
-        let mut view_group = Group::new().set("class", "g-view").set(
-            "transform",
-            format!("translate({},{})", self.margin_left, self.margin_top),
-        );
+        for (axis, position, _, _) in axes.iter() {
+            if let Some(axis) = axis {
+                axis.draw(backend, *position)?;
+            }
+        }
 
+        backend.begin_group(
+            &GroupOptions::new()
+                .set_class("g-view")
+                .set_translate(self.margin_left as f32, self.margin_top as f32),
+        );
         for view in self.views.iter() {
-            view_group.append(view.to_svg()?);
+            view.draw(backend)?;
         }
-        group.append(view_group);
+        backend.end_group();
 
         if let Some(legend_position) = self.legend_position {
             let width;
@@ -517,39 +575,214 @@ impl<'a> Chart<'a> {
                 .flat_map(|view| view.get_legend_entries())
                 .collect::<Vec<LegendEntry>>();
             let legend = Legend::new(legend_entries, width as usize);
-            let mut legend_group = legend.to_svg()?;
-            legend_group.assign("transform", format!("translate({},{})", x_offset, y_offset));
+            legend.draw(backend, (x_offset as f32, y_offset as f32));
+        }
+
+        backend.end_group();
+
+        Ok(())
+    }
+
+    /// Generate the SVG for the chart and its components.
+    fn to_svg(&self) -> Result<Group, ChartError> {
+        let mut backend = SvgBackend::new();
+        self.draw(&mut backend)?;
+        Ok(backend.into_group())
+    }
 
-            group.append(legend_group);
+    /// Generate the SVG document that represents the chart.
+    pub fn to_svg_document(&self) -> Result<Document, ChartError> {
+        let svg_content = self.to_svg()?;
+
+        Ok(Document::new()
+            .set("width", self.width)
+            .set("height", self.height)
+            .set("viewBox", (0, 0, self.width, self.height))
+            .add(svg_content))
+    }
+
+    /// Render the chart as an SVG string.
+    pub fn to_svg_string(&self) -> Result<String, ChartError> {
+        Ok(self.to_svg_document()?.to_string())
+    }
+
+    /// Write the chart as SVG into the given writer.
+    pub fn write_to<W: Write>(&self, writer: W) -> Result<(), ChartError> {
+        let document = self.to_svg_document()?;
+        svg::write(writer, &document)?;
+        Ok(())
+    }
+
+    /// Display the chart inline in a Jupyter notebook running the evcxr Rust kernel.
+    /// evcxr calls this method when the chart is the last expression of a cell.
+    pub fn evcxr_display(&self) {
+        println!("{}", self.evcxr_content());
+    }
+
+    /// Wrap the chart SVG, or the error that prevented rendering it, into an evcxr MIME envelope.
+    fn evcxr_content(&self) -> String {
+        match self.to_svg_string() {
+            Ok(svg) => format!(
+                "EVCXR_BEGIN_CONTENT image/svg+xml\n{}\nEVCXR_END_CONTENT",
+                svg
+            ),
+            Err(e) => format!("EVCXR_BEGIN_CONTENT text/plain\n{}\nEVCXR_END_CONTENT", e),
         }
+    }
 
-        Ok(group)
+    /// Render the chart as text made of braille characters, `columns` characters wide.
+    /// The output is colored with ANSI escape codes and can be printed to a terminal.
+    /// A chart whose width or height is not positive cannot be rendered and returns `ChartError::Layout`.
+    pub fn to_terminal_string(&self, columns: usize) -> Result<String, ChartError> {
+        let mut backend = TerminalBackend::new(self.width as f32, self.height as f32, columns)?;
+        self.draw(&mut backend)?;
+        Ok(backend.render())
     }
 
-    /// Save the chart to a file
-    pub fn save<P>(self, path: P) -> Result<(), String>
+    /// Save the chart to a file.
+    /// The format is chosen based on the file extension, which can be `.svg`, `.png`, `.pdf` or `.html`.
+    pub fn save<P>(self, path: P) -> Result<(), ChartError>
     where
         P: AsRef<Path>,
     {
-        if let Some("svg") = path.as_ref().extension().and_then(OsStr::to_str) {
-            match self.to_svg() {
-                Ok(svg_content) => {
-                    let document = svg::Document::new()
-                        .set("width", self.width)
-                        .set("height", self.height)
-                        .set("viewBox", (0, 0, self.width, self.height))
-                        .add(svg_content);
-
-                    svg::save(path, &document).unwrap()
-                }
-                Err(e) => {
-                    return Err(format!(
-                        "Encountered an error while saving the chart: {:?}",
-                        e
-                    ))
-                }
+        let document = self.to_svg_document()?;
+
+        match path.as_ref().extension().and_then(OsStr::to_str) {
+            Some("svg") => svg::save(path, &document)?,
+            Some("png") => {
+                let image = png::svg_to_png(&document.to_string(), self.raster_scale)?;
+                fs::write(path, image)?
+            }
+            Some("html") => {
+                let page = html::svg_to_html(&document.to_string(), &self.title);
+                fs::write(path, page)?
+            }
+            Some("pdf") => {
+                let document = pdf::svg_to_pdf(&document.to_string())?;
+                fs::write(path, document)?
+            }
+            Some(extension) => {
+                return Err(ChartError::Render(format!(
+                "Cannot save the chart as .{}, supported formats are .svg, .png, .pdf and .html",
+                extension
+            )))
+            }
+            None => {
+                return Err(ChartError::Render(
+                    "Cannot save the chart to a file without an extension".to_string(),
+                ))
             }
         };
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn svg_string_matches_written_svg() {
+        let chart = Chart::new().add_title(String::from("In memory"));
+        let svg_string = chart.to_svg_string().unwrap();
+        let mut buffer = Vec::new();
+        chart.write_to(&mut buffer).unwrap();
+
+        assert!(svg_string.starts_with("<svg"));
+        assert!(svg_string.contains("In memory"));
+        assert_eq!(String::from_utf8(buffer).unwrap(), svg_string);
+    }
+
+    #[test]
+    fn axis_label_requires_axis() {
+        let result = Chart::new().add_left_axis_label("Label");
+        assert!(matches!(result, Err(ChartError::Layout(_))));
+    }
+
+    #[test]
+    fn evcxr_content_wraps_svg() {
+        let content = Chart::new().evcxr_content();
+
+        assert!(content.starts_with("EVCXR_BEGIN_CONTENT image/svg+xml\n<svg"));
+        assert!(content.ends_with("</svg>\nEVCXR_END_CONTENT"));
+    }
+
+    #[test]
+    fn terminal_string_rejects_empty_chart() {
+        let result = Chart::new().set_width(0).to_terminal_string(80);
+        assert!(matches!(result, Err(ChartError::Layout(_))));
+    }
+
+    #[test]
+    fn save_rejects_unsupported_extension() {
+        let result = Chart::new().save("chart.gif");
+        assert!(matches!(result, Err(ChartError::Render(_))));
+    }
+
+    #[test]
+    fn plots_f64_data_beyond_f32_precision() {
+        let x = crate::ScaleLinear::<f64>::new()
+            .set_domain(vec![1e15_f64, 1e15_f64 + 100_f64])
+            .set_range(vec![0, 200]);
+        let y = crate::ScaleLinear::<f64>::new()
+            .set_domain(vec![0_f64, 1_f64])
+            .set_range(vec![100, 0]);
+        let data = vec![(1e15_f64 + 50_f64, 0.5_f64), (1e15_f64 + 75_f64, 0.25_f64)];
+        let scatter = crate::ScatterView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .load_data(&data)
+            .unwrap();
+        let line = crate::LineSeriesView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .load_data(&data)
+            .unwrap();
+        let svg_string = Chart::new()
+            .add_view(&scatter)
+            .add_view(&line)
+            .add_axis_bottom(&x)
+            .to_svg_string()
+            .unwrap();
+
+        assert!(svg_string.contains(
+            "data-x=\"1000000000000050\" data-y=\"0.5\" transform=\"translate(100,50)\""
+        ));
+        assert!(svg_string.contains("d=\"M100,50 L150,75\""));
+    }
+
+    #[test]
+    fn gridlines_are_drawn_beneath_the_views() {
+        let y = crate::ScaleLinear::new()
+            .set_domain(vec![0_f32, 10_f32])
+            .set_range(vec![100, 0])
+            .set_tick_count(2);
+        let svg_string = Chart::new()
+            .add_axis_left(&y)
+            .set_left_axis_gridlines(Gridlines::new().set_color("#123456"))
+            .to_svg_string()
+            .unwrap();
+
+        assert_eq!(svg_string.matches("stroke=\"#123456\"").count(), 3);
+        assert!(svg_string.find("gridlines").unwrap() < svg_string.find("g-view").unwrap());
+    }
+
+    #[test]
+    fn gridlines_follow_the_final_chart_size() {
+        let x = crate::ScaleLinear::new()
+            .set_domain(vec![0_f32, 10_f32])
+            .set_range(vec![0, 100])
+            .set_tick_count(1);
+        let svg_string = Chart::new()
+            .set_bottom_axis_gridlines(Gridlines::new().set_dasharray("4, 2").set_opacity(0.5))
+            .add_axis_bottom(&x)
+            .set_height(300)
+            .set_margins(50, 40, 50, 60)
+            .to_svg_string()
+            .unwrap();
+
+        assert_eq!(svg_string.matches("stroke-dasharray=\"4, 2\"").count(), 2);
+        assert!(svg_string.contains("opacity=\"0.5\""));
+        assert!(svg_string.contains("y1=\"0\" y2=\"-200\""));
+    }
+}
@@ -64,6 +64,63 @@ impl Color {
         )
     }
 
+    /// A sequential color scheme of nine colors sampled from
+    /// [viridis](https://bids.github.io/colormap/), from dark purple to yellow.
+    pub fn color_scheme_viridis() -> Vec<Self> {
+        Color::from_vec_of_hex_strings(vec!(
+            "#440154", "#472d7b", "#3b528b", "#2c728e", "#21918c", "#28ae80", "#5ec962", "#addc30", "#fde725",
+        ))
+    }
+
+    /// A sequential color scheme of nine colors from the ColorBrewer Blues, from white to dark blue.
+    pub fn color_scheme_blues() -> Vec<Self> {
+        Color::from_vec_of_hex_strings(vec!(
+            "#f7fbff", "#deebf7", "#c6dbef", "#9ecae1", "#6baed6", "#4292c6", "#2171b5", "#08519c", "#08306b",
+        ))
+    }
+
+    /// A diverging color scheme of eleven colors from the ColorBrewer RdBu, from dark red through
+    /// white to dark blue.
+    pub fn color_scheme_red_blue() -> Vec<Self> {
+        Color::from_vec_of_hex_strings(vec!(
+            "#67001f", "#b2182b", "#d6604d", "#f4a582", "#fddbc7", "#f7f7f7", "#d1e5f0", "#92c5de", "#4393c3", "#2166ac", "#053061",
+        ))
+    }
+
+    /// Create a color from its red, green and blue components.
+    pub fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Color { hex: format!("#{:02x}{:02x}{:02x}", red, green, blue) }
+    }
+
+    /// Get the red, green and blue components of a color written as `#rrggbb` or `#rgb`.
+    pub fn to_rgb(&self) -> Option<(u8, u8, u8)> {
+        let digits = self.hex.strip_prefix('#')?;
+        let component = |digits: &str| u8::from_str_radix(digits, 16).ok();
+
+        match digits.len() {
+            6 => Some((component(&digits[0..2])?, component(&digits[2..4])?, component(&digits[4..6])?)),
+            3 => Some((
+                component(&digits[0..1])? * 17,
+                component(&digits[1..2])? * 17,
+                component(&digits[2..3])? * 17,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Mix the color with another one, `t` being the share of the other color between 0 and 1.
+    /// Colors that are not written in hex notation cannot be mixed and the first one is kept.
+    pub fn interpolate(&self, other: &Color, t: f32) -> Color {
+        match (self.to_rgb(), other.to_rgb()) {
+            (Some(from), Some(to)) => {
+                let t = t.clamp(0_f32, 1_f32);
+                let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+                Color::from_rgb(mix(from.0, to.0), mix(from.1, to.1), mix(from.2, to.2))
+            },
+            _ => Color { hex: self.as_hex() },
+        }
+    }
+
     /// Represent a color as a hex string.
     pub fn as_hex(&self) -> String {
         String::from(&self.hex)
@@ -1,8 +1,7 @@
 use std::fmt::Display;
-use svg::node::element::{Group, Path};
-use svg::node::element::path::Data;
-use svg::node::Node;
+use crate::backend::{Backend, GroupOptions, ShapeStyle};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::components::scatter::ScatterPoint;
 
 /// Represents a point in a scatter plot.
@@ -26,33 +25,23 @@ impl<T: Display + Clone, U: Display + Clone> AreaSeries<T, U> {
 
 impl<T: Display + Clone, U: Display + Clone> DatumRepresentation for AreaSeries<T, U> {
 
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new()
-            .set("class", "line");
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        backend.begin_group(&GroupOptions::new().set_class("line"));
 
-        let mut data = Data::new();
-
-        for (i, point) in self.points.iter().enumerate() {
-            if i == 0 {
-                data = data.move_to((point.get_x(), point.get_y()));
-            } else {
-                data = data.line_to((point.get_x(), point.get_y()));
-            }
-        }
-
-        data = data.close();
-
-        let area = Path::new()
-            .set("fill", self.color.as_ref())
-            .set("stroke", self.color.as_ref())
-            .set("d", data);
-
-        group.append(area);
+        let points = self.points.iter()
+            .map(|point| (point.get_x(), point.get_y()))
+            .collect::<Vec<(f32, f32)>>();
+        let style = ShapeStyle::new()
+            .set_fill(&self.color)
+            .set_stroke(&self.color);
+        backend.draw_path(&points, true, &style);
 
         for point in self.points.iter() {
-            group.append(point.to_svg()?);
+            point.draw(backend)?;
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 }
@@ -1,9 +1,11 @@
-use svg::node::element::{Group, Line};
-use svg::node::Text as TextNode;
-use svg::node::element::Text;
-use svg::Node;
 use format_num::NumberFormat;
 use crate::axis::AxisPosition;
+use crate::backend::{Backend, GroupOptions, ShapeStyle, TextAnchor, TextStyle};
+use crate::components::DatumRepresentation;
+use crate::error::ChartError;
+
+/// The distance from a break to each end of the gap it leaves in the axis line.
+const AXIS_BREAK_GAP: f32 = 3_f32;
 
 /// A simple struct that represents an axis line.
 pub(crate) struct AxisLine {
@@ -11,26 +13,145 @@ pub(crate) struct AxisLine {
     y1: f32,
     x2: f32,
     y2: f32,
+    gaps: Vec<f32>,
 }
 
 impl AxisLine {
     /// Create a new instance of axis line.
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
-        Self { x1, y1, x2, y2 }
+        Self { x1, y1, x2, y2, gaps: Vec::new() }
+    }
+
+    /// Leave a gap in the line around each of the given break offsets along the axis.
+    pub fn set_gaps(mut self, mut offsets: Vec<f32>) -> Self {
+        offsets.sort_by(|a, b| a.total_cmp(b));
+        self.gaps = offsets;
+        self
+    }
+
+    /// Draw the axis line on the given backend, split into segments around the gaps.
+    pub fn draw(&self, backend: &mut dyn Backend) {
+        let style = ShapeStyle::new()
+            .set_stroke("#bbbbbb")
+            .set_stroke_width(1_f32)
+            .set_crisp_edges(true);
+
+        // The segments are laid out along the varying coordinate of the line.
+        let vertical = self.x1 == self.x2;
+        let (start, end) = if vertical { (self.y1, self.y2) } else { (self.x1, self.x2) };
+        let point = |along: f32| if vertical { (self.x1, along) } else { (along, self.y1) };
+
+        let mut from = start.min(end);
+        for gap in self.gaps.iter() {
+            if gap - AXIS_BREAK_GAP > from {
+                backend.draw_line(point(from), point(gap - AXIS_BREAK_GAP), &style);
+            }
+            from = from.max(gap + AXIS_BREAK_GAP);
+        }
+        if from < start.max(end) {
+            backend.draw_line(point(from), point(start.max(end)), &style);
+        }
+    }
+}
+
+/// The style of the gridlines drawn across the plot area at the ticks of an axis.
+#[derive(Debug, Clone)]
+pub struct Gridlines {
+    color: String,
+    dasharray: Option<String>,
+    opacity: f32,
+}
+
+impl Default for Gridlines {
+    fn default() -> Self {
+        Self {
+            color: "#dddddd".to_owned(),
+            dasharray: None,
+            opacity: 1_f32,
+        }
+    }
+}
+
+impl Gridlines {
+    /// Create solid light gray gridlines.
+    pub fn new() -> Self {
+        Gridlines::default()
+    }
+
+    /// Set the color of the gridlines.
+    pub fn set_color(mut self, color: &str) -> Self {
+        self.color = color.to_owned();
+        self
+    }
+
+    /// Set the dash pattern of the gridlines, e.g. `4, 2`.
+    pub fn set_dasharray(mut self, dasharray: &str) -> Self {
+        self.dasharray = Some(dasharray.to_owned());
+        self
+    }
+
+    /// Set the opacity of the gridlines, clamped between 0 (transparent) and 1 (opaque).
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0_f32, 1_f32);
+        self
+    }
+
+    /// Draw a gridline of the given length across the plot area at each of the given offsets along the axis.
+    pub(crate) fn draw(&self, backend: &mut dyn Backend, offsets: &[f32], length: f32, axis_position: AxisPosition) {
+        let mut style = ShapeStyle::new()
+            .set_stroke(&self.color)
+            .set_stroke_width(1_f32)
+            .set_opacity(self.opacity)
+            .set_crisp_edges(true);
+        if let Some(dasharray) = &self.dasharray {
+            style = style.set_stroke_dasharray(dasharray);
+        }
+
+        for offset in offsets.iter() {
+            let (from, to) = match axis_position {
+                AxisPosition::Left => ((0_f32, *offset), (length, *offset)),
+                AxisPosition::Bottom => ((*offset, 0_f32), (*offset, -length)),
+                AxisPosition::Right => ((0_f32, *offset), (-length, *offset)),
+                AxisPosition::Top => ((*offset, 0_f32), (*offset, length)),
+            };
+            backend.draw_line(from, to, &style);
+        }
+    }
+}
+
+/// A struct to represent the marker of a break in an axis, where part of the domain is left out.
+pub(crate) struct AxisBreak {
+    axis_position: AxisPosition,
+    offset: f32,
+}
+
+impl AxisBreak {
+    /// Create a new instance of axis break at the given offset along the axis.
+    pub fn new(offset: f32, axis_position: AxisPosition) -> Self {
+        Self { axis_position, offset }
     }
+}
 
-    /// Render the axis line to svg.
-    pub fn to_svg(&self) -> Result<Line, String> {
-        let line = Line::new()
-            .set("x1", self.x1)
-            .set("y1", self.y1)
-            .set("x2", self.x2)
-            .set("y2", self.y2)
-            .set("shape-rendering", "crispEdges")
-            .set("stroke-width", 1)
-            .set("stroke", "#bbbbbb");
+impl DatumRepresentation for AxisBreak {
+    /// Draw the break marker on the given backend, two slanted lines across the gap in the axis line.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        // The points are laid out along a horizontal axis and swapped for a vertical one.
+        let point = |along: f32, across: f32| match self.axis_position {
+            AxisPosition::Top | AxisPosition::Bottom => (self.offset + along, across),
+            AxisPosition::Left | AxisPosition::Right => (across, self.offset - along),
+        };
 
-        Ok(line)
+        backend.begin_group(&GroupOptions::new().set_class("axis-break"));
+
+        let marker_style = ShapeStyle::new()
+            .set_stroke("#bbbbbb")
+            .set_stroke_width(1_f32);
+        backend.draw_line(point(-6_f32, 5_f32), point(-2_f32, -5_f32), &marker_style);
+        backend.draw_line(point(2_f32, 5_f32), point(6_f32, -5_f32), &marker_style);
+
+        backend.end_group();
+
+        Ok(())
     }
 }
 
@@ -82,81 +203,199 @@ impl AxisTick {
         self.label_font_size = format!("{}px", size);
     }
 
-    /// Render the axis tick to svg.
-    pub fn to_svg(&self) -> Result<Group, String> {
-        let formatted_label = if self.label_format.is_some() {
+    /// Return the offset of the tick along the axis.
+    pub fn get_tick_offset(&self) -> f32 {
+        self.tick_offset
+    }
+}
+
+impl DatumRepresentation for AxisTick {
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        let formatted_label = if let Some(label_format) = &self.label_format {
+            let value = self.label.parse::<f64>().map_err(|_| {
+                ChartError::Layout(format!(
+                    "Cannot apply the \"{}\" format to the non-numeric tick label \"{}\".",
+                    label_format, self.label
+                ))
+            })?;
             let formatter = NumberFormat::new();
-            formatter.format(self.label_format.as_ref().unwrap(), self.label.parse::<f64>().unwrap()).replace('G', "B")
+            formatter.format(label_format, value).replace('G', "B")
         } else {
             self.label.to_owned()
         };
         let offsets: (f32, f32);
         let tick_line_p2: (isize, isize);
         let tick_label_offset: (isize, isize);
-        let tick_label_text_anchor: &str;
+        let tick_label_text_anchor: TextAnchor;
 
         match self.axis_position {
             AxisPosition::Left => {
                 offsets = (0_f32, self.tick_offset);
                 tick_line_p2 = (-6, 0);
                 tick_label_offset = (-(self.label_offset as isize), 0);
-                tick_label_text_anchor = "end";
+                tick_label_text_anchor = TextAnchor::End;
             },
             AxisPosition::Bottom => {
                 offsets = (self.tick_offset, 0_f32);
                 tick_line_p2 = (0, 6);
                 tick_label_offset = (0, self.label_offset as isize);
-                tick_label_text_anchor = "middle";
+                tick_label_text_anchor = TextAnchor::Middle;
             },
             AxisPosition::Right => {
                 offsets = (0_f32, self.tick_offset);
                 tick_line_p2 = (6, 0);
                 tick_label_offset = (self.label_offset as isize, 0);
-                tick_label_text_anchor = "start";
+                tick_label_text_anchor = TextAnchor::Start;
             },
             AxisPosition::Top => {
                 offsets = (self.tick_offset, 0_f32);
                 tick_line_p2 = (0, -6);
                 tick_label_offset = (0, -(self.label_offset as isize));
-                tick_label_text_anchor = "middle";
+                tick_label_text_anchor = TextAnchor::Middle;
             },
         };
 
-        let mut group = Group::new()
-            .set("class", "tick")
-            .set("transform", format!("translate({},{})", offsets.0, offsets.1));
-
-        let tick_line = Line::new()
-            .set("x1", 0)
-            .set("y1", 0)
-            .set("x2", tick_line_p2.0)
-            .set("y2", tick_line_p2.1)
-            .set("shape-rendering", "crispEdges")
-            .set("stroke", "#bbbbbb")
-            .set("stroke-width", "1px");
-
-        let tick_label = Text::new()
-            .set("transform", format!("rotate({},{},{})", self.label_rotation, tick_label_offset.0, tick_label_offset.1))
-            .set("x", tick_label_offset.0)
-            .set("y", tick_label_offset.1)
-            .set("dy", ".35em")
-            .set("text-anchor", tick_label_text_anchor)
-            .set("font-size", self.label_font_size.clone())
-            .set("font-family", "sans-serif")
-            .set("fill", "#777")
-            .add(TextNode::new(formatted_label));
-
-        group.append(tick_line);
-        group.append(tick_label);
-
-        Ok(group)
+        backend.begin_group(
+            &GroupOptions::new()
+                .set_class("tick")
+                .set_translate(offsets.0, offsets.1),
+        );
+
+        let tick_line_style = ShapeStyle::new()
+            .set_stroke("#bbbbbb")
+            .set_stroke_width(1_f32)
+            .set_crisp_edges(true);
+        backend.draw_line(
+            (0_f32, 0_f32),
+            (tick_line_p2.0 as f32, tick_line_p2.1 as f32),
+            &tick_line_style,
+        );
+
+        let tick_label_style = TextStyle::new()
+            .set_anchor(tick_label_text_anchor)
+            .set_vertically_centered(true)
+            .set_rotation(self.label_rotation as f32)
+            .set_font_size(&self.label_font_size);
+        backend.draw_text(
+            tick_label_offset.0 as f32,
+            tick_label_offset.1 as f32,
+            &formatted_label,
+            &tick_label_style,
+        );
+
+        backend.end_group();
+
+        Ok(())
+    }
+}
+
+
+/// A struct to represent a group of axis ticks, drawn as a second tier of labels.
+pub struct AxisTickGroup {
+    axis_position: AxisPosition,
+    label_offset: usize,
+    start: f32,
+    end: f32,
+    label: String,
+    label_font_size: String,
+    separator: Option<(f32, usize)>,
+}
+
+impl AxisTickGroup {
+    /// Create a new instance of AxisTickGroup spanning from start to end along the axis.
+    pub fn new(start: f32, end: f32, label_offset: usize, label: String, label_font_size_opt: Option<usize>, axis_position: AxisPosition) -> Self {
+        let mut new_axis_tick_group = Self {
+            axis_position,
+            label_offset,
+            start,
+            end,
+            label,
+            label_font_size: "12px".to_owned(),
+            separator: None,
+        };
+
+        if let Some(size) = label_font_size_opt {
+            new_axis_tick_group.set_label_font_size(size);
+        };
+
+        new_axis_tick_group
+    }
+
+    /// Set label font size.
+    pub fn set_label_font_size(&mut self, size: usize) {
+        self.label_font_size = format!("{}px", size);
+    }
+
+    /// Draw a separator of the given length at the given offset along the axis.
+    pub fn set_separator(&mut self, offset: f32, length: usize) {
+        self.separator = Some((offset, length));
     }
 }
 
+impl DatumRepresentation for AxisTickGroup {
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        let center = (self.start + self.end) / 2_f32;
+        let label_offset = self.label_offset as f32;
+        let label_position: (f32, f32);
+        let label_rotation: f32;
+
+        match self.axis_position {
+            AxisPosition::Left => {
+                label_position = (-label_offset, center);
+                label_rotation = -90_f32;
+            },
+            AxisPosition::Bottom => {
+                label_position = (center, label_offset);
+                label_rotation = 0_f32;
+            },
+            AxisPosition::Right => {
+                label_position = (label_offset, center);
+                label_rotation = 90_f32;
+            },
+            AxisPosition::Top => {
+                label_position = (center, -label_offset);
+                label_rotation = 0_f32;
+            },
+        };
+
+        backend.begin_group(&GroupOptions::new().set_class("tick-group"));
+
+        if let Some((offset, length)) = self.separator {
+            let length = length as f32;
+            let separator_style = ShapeStyle::new()
+                .set_stroke("#bbbbbb")
+                .set_stroke_width(1_f32)
+                .set_crisp_edges(true);
+            let separator_p2 = match self.axis_position {
+                AxisPosition::Left => (-length, offset),
+                AxisPosition::Bottom => (offset, length),
+                AxisPosition::Right => (length, offset),
+                AxisPosition::Top => (offset, -length),
+            };
+            let separator_p1 = match self.axis_position {
+                AxisPosition::Left | AxisPosition::Right => (0_f32, offset),
+                AxisPosition::Top | AxisPosition::Bottom => (offset, 0_f32),
+            };
+            backend.draw_line(separator_p1, separator_p2, &separator_style);
+        }
+
+        let label_style = TextStyle::new()
+            .set_anchor(TextAnchor::Middle)
+            .set_vertically_centered(true)
+            .set_rotation(label_rotation)
+            .set_font_size(&self.label_font_size);
+        backend.draw_text(label_position.0, label_position.1, &self.label, &label_style);
+
+        backend.end_group();
+
+        Ok(())
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::backend::svg::SvgBackend;
     #[test]
     fn tick_label_font_size_test_default() {
         let tick = AxisTick::new(16.0,
@@ -192,4 +431,30 @@ mod tests {
         assert_eq!(tick.label_font_size, "20px");
 
     }
+
+    #[test]
+    fn tick_label_format_requires_number() {
+        let mut tick = AxisTick::new(16.0,
+            16,
+            0, "label".to_owned(),
+            None,
+            AxisPosition::Bottom);
+
+        tick.set_label_format(".2s");
+
+        let mut backend = SvgBackend::new();
+        assert!(matches!(tick.draw(&mut backend), Err(ChartError::Layout(_))));
+    }
+
+    #[test]
+    fn axis_line_leaves_gaps_at_breaks() {
+        let line = AxisLine::new(0_f32, 0_f32, 0_f32, 100_f32).set_gaps(vec![60_f32, 20_f32]);
+
+        let mut backend = SvgBackend::new();
+        line.draw(&mut backend);
+        let svg = backend.into_group().to_string();
+
+        assert_eq!(svg.matches("<line").count(), 3);
+        assert!(svg.contains("y1=\"23\" y2=\"57\""));
+    }
 }
@@ -1,13 +1,12 @@
+use crate::backend::{Backend, GroupOptions, ShapeStyle, TextAnchor, TextStyle};
 use crate::chart::Orientation;
 use crate::components::DatumRepresentation;
-use svg::node::element::Group;
-use svg::node::element::Rectangle;
-use svg::node::element::Text;
-use svg::node::Node;
-use svg::node::Text as TextNode;
+use crate::error::ChartError;
 
 /// Set the position of a bar's label.
 #[derive(Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum BarLabelPosition {
     StartOutside,
     StartInside,
@@ -17,14 +16,14 @@ pub enum BarLabelPosition {
 }
 
 /// Represents a block within a bar.
-/// The first tuple element represents the starting position, the second
-/// one is the size of that block and the third one is the color.
+/// The tuple elements are the starting and ending positions, the value
+/// the block represents, its color and the key of its dataset.
 #[derive(Debug)]
-pub struct BarBlock(f32, f32, f32, String);
+pub struct BarBlock(f32, f32, f32, String, String);
 
 impl BarBlock {
-    pub fn new(start: f32, end: f32, size: f32, color: String) -> Self {
-        Self(start, end, size, color)
+    pub fn new(start: f32, end: f32, size: f32, color: String, key: String) -> Self {
+        Self(start, end, size, color, key)
     }
 }
 
@@ -81,7 +80,7 @@ impl Bar {
 }
 
 impl DatumRepresentation for Bar {
-    fn to_svg(&self) -> Result<Group, String> {
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
         let (bar_group_offset_x, bar_group_offset_y) = {
             match self.orientation {
                 Orientation::Vertical => (self.offset, 0_f32),
@@ -89,94 +88,103 @@ impl DatumRepresentation for Bar {
             }
         };
 
-        let mut group = Group::new()
-            .set(
-                "transform",
-                format!("translate({bar_group_offset_x},{bar_group_offset_y})"),
-            )
-            .set("class", "bar");
-
-        let (x_attr, y_attr, width_attr, height_attr) = match self.orientation {
-            Orientation::Horizontal => ("x", "y", "width", "height"),
-            Orientation::Vertical => ("y", "x", "height", "width"),
-        };
+        backend.begin_group(
+            &GroupOptions::new()
+                .set_class("bar")
+                .set_translate(bar_group_offset_x, bar_group_offset_y),
+        );
 
         for block in self.blocks.iter() {
-            let block_rect = Rectangle::new()
-                .set(x_attr, block.0)
-                .set(y_attr, 0)
-                .set(width_attr, block.1 - block.0)
-                .set(height_attr, self.bar_width)
-                .set("shape-rendering", "crispEdges")
-                .set("fill", block.3.as_ref());
+            let mut block_options = GroupOptions::new()
+                .set_class("bar-block")
+                .add_data_attribute("category", &self.category)
+                .add_data_attribute("value", &block.2.to_string());
+            if !block.4.is_empty() {
+                block_options = block_options.add_data_attribute("key", &block.4);
+            }
+            backend.begin_group(&block_options);
 
-            group.append(block_rect);
+            let block_style = ShapeStyle::new().set_fill(&block.3).set_crisp_edges(true);
+            match self.orientation {
+                Orientation::Horizontal => backend.draw_rect(
+                    block.0,
+                    0_f32,
+                    block.1 - block.0,
+                    self.bar_width,
+                    &block_style,
+                ),
+                Orientation::Vertical => backend.draw_rect(
+                    0_f32,
+                    block.0,
+                    self.bar_width,
+                    block.1 - block.0,
+                    &block_style,
+                ),
+            }
 
             // Display labels if needed.
             if self.label_visible {
-                let (label_x_attr_value, text_anchor) = match self.label_position {
+                let (label_position, text_anchor) = match self.label_position {
                     BarLabelPosition::StartOutside
                         if self.orientation == Orientation::Horizontal =>
                     {
-                        (block.0 - 12_f32, "end")
+                        (block.0 - 12_f32, TextAnchor::End)
                     }
                     BarLabelPosition::StartOutside if self.orientation == Orientation::Vertical => {
-                        (block.1 + 16_f32, "middle")
+                        (block.1 + 16_f32, TextAnchor::Middle)
                     }
                     BarLabelPosition::StartInside
                         if self.orientation == Orientation::Horizontal =>
                     {
-                        (block.0 + 12_f32, "start")
+                        (block.0 + 12_f32, TextAnchor::Start)
                     }
                     BarLabelPosition::StartInside if self.orientation == Orientation::Vertical => {
-                        (block.1 - 16_f32, "middle")
+                        (block.1 - 16_f32, TextAnchor::Middle)
                     }
                     BarLabelPosition::Center if self.orientation == Orientation::Horizontal => {
-                        (block.0 + (block.1 - block.0) / 2_f32, "middle")
+                        (block.0 + (block.1 - block.0) / 2_f32, TextAnchor::Middle)
                     }
                     BarLabelPosition::Center if self.orientation == Orientation::Vertical => {
-                        (block.0 + (block.1 - block.0) / 2_f32, "middle")
+                        (block.0 + (block.1 - block.0) / 2_f32, TextAnchor::Middle)
                     }
                     BarLabelPosition::EndInside if self.orientation == Orientation::Horizontal => {
-                        (block.1 - 12_f32, "end")
+                        (block.1 - 12_f32, TextAnchor::End)
                     }
                     BarLabelPosition::EndInside if self.orientation == Orientation::Vertical => {
-                        (block.0 + 16_f32, "middle")
+                        (block.0 + 16_f32, TextAnchor::Middle)
                     }
                     BarLabelPosition::EndOutside if self.orientation == Orientation::Horizontal => {
-                        (block.1 + 12_f32, "start")
+                        (block.1 + 12_f32, TextAnchor::Start)
                     }
                     BarLabelPosition::EndOutside if self.orientation == Orientation::Vertical => {
-                        (block.0 - 16_f32, "middle")
+                        (block.0 - 16_f32, TextAnchor::Middle)
                     }
-                    _ => (0_f32, "middle"), // this is needed to get rid of compiler warning of exhaustively covering match pattern.
+                    _ => (0_f32, TextAnchor::Middle), // this is needed to get rid of compiler warning of exhaustively covering match pattern.
                 };
 
                 let label_text = match &self.rounding_precision {
                     None => block.2.to_string(),
-                    Some(nr_of_digits) => format!(
-                        "{:.1$}",
-                        block.2.to_string().parse::<f32>().unwrap(),
-                        nr_of_digits
-                    ),
+                    Some(nr_of_digits) => format!("{:.1$}", block.2, nr_of_digits),
+                };
+
+                let label_style = TextStyle::new()
+                    .set_anchor(text_anchor)
+                    .set_vertically_centered(true)
+                    .set_fill("#333")
+                    .set_font_size(&self.label_font_size);
+                let (label_x, label_y) = match self.orientation {
+                    Orientation::Horizontal => (label_position, self.bar_width / 2_f32),
+                    Orientation::Vertical => (self.bar_width / 2_f32, label_position),
                 };
 
-                let label = Text::new()
-                    .set(x_attr, label_x_attr_value)
-                    .set(y_attr, self.bar_width / 2_f32)
-                    .set("text-anchor", text_anchor)
-                    .set("dy", ".35em")
-                    .set("font-family", "sans-serif")
-                    .set("fill", "#333")
-                    .set("font-size", self.label_font_size.clone())
-                    .add(TextNode::new(label_text));
-
-                group.append(label);
+                backend.draw_text(label_x, label_y, &label_text, &label_style);
             }
+
+            backend.end_group();
         }
 
-        // svg::save("bar-vert.svg", &group).unwrap();
+        backend.end_group();
 
-        Ok(group)
+        Ok(())
     }
 }
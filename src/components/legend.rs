@@ -1,7 +1,4 @@
-use svg::node::element::{Group, Circle, Rectangle, Line};
-use svg::Node;
-use svg::node::Text as TextNode;
-use svg::node::element::Text;
+use crate::backend::{Backend, GroupOptions, ShapeStyle, TextStyle};
 use crate::MarkerType;
 
 /// Represents the possible marker types that a legend entry can have.
@@ -66,71 +63,33 @@ impl LegendEntry {
         avg_letter_width * self.label.len() + self.marker_size * 2 + self.marker_to_label_gap
     }
 
-    pub fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new()
-            .set("class", "legend-entry");
+    /// Draw the legend entry on the given backend, offset from the legend origin.
+    pub fn draw(&self, backend: &mut dyn Backend, offset: (f32, f32)) {
+        backend.begin_group(&GroupOptions::new().set_class("legend-entry").set_translate(offset.0, offset.1));
 
+        let size = self.marker_size as f32;
+        let line_style = ShapeStyle::new()
+            .set_stroke(&self.color)
+            .set_stroke_width(2_f32);
         match self.marker_type {
-            LegendMarkerType::Circle => group.append(
-                Circle::new()
-                    .set("cx", self.marker_size)
-                    .set("cy", self.marker_size)
-                    .set("r", self.marker_size)
-                    .set("fill", self.color.as_ref())
-                    .set("stroke", "none")
-            ),
-            LegendMarkerType::Square => group.append(
-                Rectangle::new()
-                    .set("x", 0)
-                    .set("y", 0)
-                    .set("width", 2 * self.marker_size)
-                    .set("height", 2 * self.marker_size)
-                    .set("fill", self.color.as_ref())
-                    .set("stroke", "none")
-            ),
+            LegendMarkerType::Circle => backend.draw_circle(size, size, size, &ShapeStyle::new().set_fill(&self.color)),
+            LegendMarkerType::Square => backend.draw_rect(0_f32, 0_f32, 2_f32 * size, 2_f32 * size, &ShapeStyle::new().set_fill(&self.color)),
             LegendMarkerType::X => {
-                group.append(
-                    Line::new()
-                        .set("x1", 0)
-                        .set("y1", 0)
-                        .set("x2", 2 * self.marker_size)
-                        .set("y2", 2 * self.marker_size)
-                        .set("stroke", self.color.as_ref())
-                        .set("stroke-width", "2px")
-                );
-                group.append(
-                    Line::new()
-                        .set("x1", 2 * self.marker_size)
-                        .set("y1", 0)
-                        .set("x2", 0)
-                        .set("y2", 2 * self.marker_size)
-                        .set("stroke", self.color.as_ref())
-                        .set("stroke-width", "2px")
-                )
+                backend.draw_line((0_f32, 0_f32), (2_f32 * size, 2_f32 * size), &line_style);
+                backend.draw_line((2_f32 * size, 0_f32), (0_f32, 2_f32 * size), &line_style);
             },
-            LegendMarkerType::Line => group.append(
-                Line::new()
-                    .set("x1", 0)
-                    .set("y1", self.marker_size)
-                    .set("x2", 2 * self.marker_size)
-                    .set("y2", self.marker_size)
-                    .set("stroke", self.color.as_ref())
-                    .set("stroke-width", "2px")
-                    .set("stroke-dasharray", self.stroke_type.as_ref())
+            LegendMarkerType::Line => backend.draw_line(
+                (0_f32, size),
+                (2_f32 * size, size),
+                &line_style.set_stroke_dasharray(&self.stroke_type),
             ),
         }
 
-        group.append(
-            Text::new()
-                .set("x", 2 * self.marker_size + self.marker_to_label_gap)
-                .set("y", self.marker_size)
-                .set("dy", ".35em")
-                .set("font-family", "sans-serif")
-                .set("fill", "#777")
-                .set("font-size", self.font_size.clone())
-                .add(TextNode::new(self.label.clone()))
-        );
+        let label_style = TextStyle::new()
+            .set_vertically_centered(true)
+            .set_font_size(&self.font_size);
+        backend.draw_text((2 * self.marker_size + self.marker_to_label_gap) as f32, size, &self.label, &label_style);
 
-        Ok(group)
+        backend.end_group();
     }
-}
\ No newline at end of file
+}
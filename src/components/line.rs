@@ -1,8 +1,7 @@
 use std::fmt::Display;
-use svg::node::element::{Group, Path};
-use svg::node::element::path::Data;
-use svg::node::Node;
+use crate::backend::{Backend, GroupOptions, ShapeStyle};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::components::scatter::ScatterPoint;
 
 /// Represents a point in a scatter plot.
@@ -10,6 +9,7 @@ use crate::components::scatter::ScatterPoint;
 pub struct LineSeries<T: Display, U: Display> {
     points: Vec<ScatterPoint<T, U>>,
     color: String,
+    key: String,
 }
 
 impl<T: Display, U: Display> LineSeries<T, U> {
@@ -20,38 +20,40 @@ impl<T: Display, U: Display> LineSeries<T, U> {
         Self {
             points,
             color,
+            key: String::new(),
         }
     }
+
+    /// Set the key of the dataset the series represents.
+    pub fn set_key(mut self, key: String) -> Self {
+        self.key = key;
+        self
+    }
 }
 
 impl<T: Display, U: Display> DatumRepresentation for LineSeries<T, U> {
 
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new()
-            .set("class", "line");
-
-        let mut data = Data::new();
-
-        for (i, point) in self.points.iter().enumerate() {
-            if i == 0 {
-                data = data.move_to((point.get_x(), point.get_y()));
-            } else {
-                data = data.line_to((point.get_x(), point.get_y()));
-            }
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        let mut options = GroupOptions::new().set_class("line");
+        if !self.key.is_empty() {
+            options = options.add_data_attribute("key", &self.key);
         }
+        backend.begin_group(&options);
 
-        let line = Path::new()
-            .set("fill", "none")
-            .set("stroke", self.color.as_ref())
-            .set("stroke-width", 2)
-            .set("d", data);
-
-        group.append(line);
+        let points = self.points.iter()
+            .map(|point| (point.get_x(), point.get_y()))
+            .collect::<Vec<(f32, f32)>>();
+        let style = ShapeStyle::new()
+            .set_stroke(&self.color)
+            .set_stroke_width(2_f32);
+        backend.draw_path(&points, false, &style);
 
         for point in self.points.iter() {
-            group.append(point.to_svg()?);
+            point.draw(backend)?;
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 }
@@ -1,4 +1,5 @@
-use svg::node::element::Group;
+use crate::backend::Backend;
+use crate::error::ChartError;
 
 pub(crate) mod bar;
 pub(crate) mod axis;
@@ -9,5 +10,6 @@ pub(crate) mod area;
 
 /// A trait that defines behavior of chart components.
 pub trait DatumRepresentation {
-    fn to_svg(&self) -> Result<Group, String>;
-}
\ No newline at end of file
+    /// Draw the component on the given backend.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError>;
+}
@@ -1,12 +1,12 @@
+use crate::backend::{Backend, GroupOptions, ShapeStyle, TextAnchor, TextStyle};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use std::fmt::Display;
-use svg::node::element::Text;
-use svg::node::element::{Circle, Group, Line, Rectangle};
-use svg::node::Node;
-use svg::node::Text as TextNode;
 
 /// Define the possible types of points in a scatter plot.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MarkerType {
     Circle,
     Square,
@@ -15,6 +15,8 @@ pub enum MarkerType {
 
 /// Define the possible locations of a point's label.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum PointLabelPosition {
     N,
     NE,
@@ -39,6 +41,7 @@ pub struct ScatterPoint<T: Display, U: Display> {
     x_label: T,
     y_label: U,
     color: String,
+    key: String,
 }
 
 impl<T: Display, U: Display> ScatterPoint<T, U> {
@@ -66,9 +69,16 @@ impl<T: Display, U: Display> ScatterPoint<T, U> {
             x_label,
             y_label,
             color,
+            key: String::new(),
         }
     }
 
+    /// Set the key of the dataset the point belongs to.
+    pub fn set_key(mut self, key: String) -> Self {
+        self.key = key;
+        self
+    }
+
     /// Return the x coordinate of the point.
     pub fn get_x(&self) -> f32 {
         self.x
@@ -81,114 +91,85 @@ impl<T: Display, U: Display> ScatterPoint<T, U> {
 }
 
 impl<T: Display, U: Display> DatumRepresentation for ScatterPoint<T, U> {
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new()
-            .set("transform", format!("translate({},{})", self.x, self.y))
-            .set("class", "scatter-point");
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        let mut options = GroupOptions::new()
+            .set_class("scatter-point")
+            .set_translate(self.x, self.y)
+            .add_data_attribute("x", &self.x_label.to_string())
+            .add_data_attribute("y", &self.y_label.to_string());
+        if !self.key.is_empty() {
+            options = options.add_data_attribute("key", &self.key);
+        }
+        backend.begin_group(&options);
 
+        let size = self.marker_size as f32;
         match self.marker_type {
             MarkerType::Circle if self.point_visible => {
-                group.append(
-                    Circle::new()
-                        .set("cx", 0)
-                        .set("cy", 0)
-                        .set("r", self.marker_size)
-                        .set("fill", self.color.as_ref()),
-                );
+                backend.draw_circle(0_f32, 0_f32, size, &ShapeStyle::new().set_fill(&self.color));
             }
             MarkerType::Square if self.point_visible => {
-                group.append(
-                    Rectangle::new()
-                        .set("x", -(self.marker_size as i32))
-                        .set("y", -(self.marker_size as i32))
-                        .set("width", 2 * self.marker_size)
-                        .set("height", 2 * self.marker_size)
-                        .set("fill", self.color.as_ref()),
+                backend.draw_rect(
+                    -size,
+                    -size,
+                    2_f32 * size,
+                    2_f32 * size,
+                    &ShapeStyle::new().set_fill(&self.color),
                 );
             }
             MarkerType::X if self.point_visible => {
-                group.append(
-                    Group::new()
-                        .add(
-                            Line::new()
-                                .set("x1", -(self.marker_size as i32))
-                                .set("y1", -(self.marker_size as i32))
-                                .set("x2", self.marker_size)
-                                .set("y2", self.marker_size)
-                                .set("stroke-width", "2px")
-                                .set("stroke", self.color.as_ref()),
-                        )
-                        .add(
-                            Line::new()
-                                .set("x1", self.marker_size)
-                                .set("y1", -(self.marker_size as i32))
-                                .set("x2", -(self.marker_size as i32))
-                                .set("y2", self.marker_size)
-                                .set("stroke-width", "2px")
-                                .set("stroke", self.color.as_ref()),
-                        ),
-                );
+                let style = ShapeStyle::new()
+                    .set_stroke(&self.color)
+                    .set_stroke_width(2_f32);
+                backend.begin_group(&GroupOptions::new());
+                backend.draw_line((-size, -size), (size, size), &style);
+                backend.draw_line((size, -size), (-size, size), &style);
+                backend.end_group();
             }
             _ => {}
         };
 
         if self.label_visible {
-            let mut point_label = Text::new()
-                .set("dy", ".35em")
-                .set("font-family", "sans-serif")
-                .set("fill", "#333")
-                .set("font-size", "14px")
-                .add(TextNode::new(format!(
-                    "({}, {})",
-                    self.x_label, self.y_label
-                )));
-
-            let label_offset = self.marker_size as isize;
-            match self.label_position {
-                PointLabelPosition::N => {
-                    point_label.assign("x", 0);
-                    point_label.assign("y", -label_offset - 12);
-                    point_label.assign("text-anchor", "middle");
-                }
-                PointLabelPosition::NE => {
-                    point_label.assign("x", label_offset + 4);
-                    point_label.assign("y", -label_offset - 8);
-                    point_label.assign("text-anchor", "start");
-                }
-                PointLabelPosition::E => {
-                    point_label.assign("x", label_offset + 8);
-                    point_label.assign("y", 0);
-                    point_label.assign("text-anchor", "start");
-                }
-                PointLabelPosition::SE => {
-                    point_label.assign("x", label_offset + 4);
-                    point_label.assign("y", label_offset + 8);
-                    point_label.assign("text-anchor", "start");
-                }
-                PointLabelPosition::S => {
-                    point_label.assign("x", 0);
-                    point_label.assign("y", label_offset + 12);
-                    point_label.assign("text-anchor", "middle");
-                }
+            let label_offset = size;
+            let (x, y, anchor) = match self.label_position {
+                PointLabelPosition::N => (0_f32, -label_offset - 12_f32, TextAnchor::Middle),
+                PointLabelPosition::NE => (
+                    label_offset + 4_f32,
+                    -label_offset - 8_f32,
+                    TextAnchor::Start,
+                ),
+                PointLabelPosition::E => (label_offset + 8_f32, 0_f32, TextAnchor::Start),
+                PointLabelPosition::SE => (
+                    label_offset + 4_f32,
+                    label_offset + 8_f32,
+                    TextAnchor::Start,
+                ),
+                PointLabelPosition::S => (0_f32, label_offset + 12_f32, TextAnchor::Middle),
                 PointLabelPosition::SW => {
-                    point_label.assign("x", -label_offset - 4);
-                    point_label.assign("y", label_offset + 8);
-                    point_label.assign("text-anchor", "end");
-                }
-                PointLabelPosition::W => {
-                    point_label.assign("x", -label_offset - 8);
-                    point_label.assign("y", 0);
-                    point_label.assign("text-anchor", "end");
+                    (-label_offset - 4_f32, label_offset + 8_f32, TextAnchor::End)
                 }
-                PointLabelPosition::NW => {
-                    point_label.assign("x", -label_offset - 4);
-                    point_label.assign("y", -label_offset - 8);
-                    point_label.assign("text-anchor", "end");
-                }
-            }
-            group.append(point_label);
+                PointLabelPosition::W => (-label_offset - 8_f32, 0_f32, TextAnchor::End),
+                PointLabelPosition::NW => (
+                    -label_offset - 4_f32,
+                    -label_offset - 8_f32,
+                    TextAnchor::End,
+                ),
+            };
+
+            let label_style = TextStyle::new()
+                .set_anchor(anchor)
+                .set_vertically_centered(true)
+                .set_fill("#333")
+                .set_font_size("14px");
+            backend.draw_text(
+                x,
+                y,
+                &format!("({}, {})", self.x_label, self.y_label),
+                &label_style,
+            );
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 }
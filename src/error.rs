@@ -0,0 +1,45 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// The errors that can occur while building, rendering or saving a chart.
+#[derive(Debug)]
+pub enum ChartError {
+    /// A scale is misconfigured or was asked for a value outside of its domain.
+    Scale(String),
+    /// The provided data cannot be represented by a view.
+    Data(String),
+    /// The chart components cannot be laid out, e.g. a label was set on a missing axis.
+    Layout(String),
+    /// The chart cannot be rendered into the requested output format.
+    Render(String),
+    /// Reading or writing the chart failed.
+    Io(io::Error),
+}
+
+impl fmt::Display for ChartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChartError::Scale(message) => write!(f, "scale error: {}", message),
+            ChartError::Data(message) => write!(f, "data error: {}", message),
+            ChartError::Layout(message) => write!(f, "layout error: {}", message),
+            ChartError::Render(message) => write!(f, "render error: {}", message),
+            ChartError::Io(error) => write!(f, "io error: {}", error),
+        }
+    }
+}
+
+impl Error for ChartError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ChartError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ChartError {
+    fn from(error: io::Error) -> Self {
+        ChartError::Io(error)
+    }
+}
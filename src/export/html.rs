@@ -0,0 +1,102 @@
+/// The stylesheet of the interactive page.
+const STYLE: &str = r#"
+body { margin: 0; font-family: sans-serif; }
+.chart { position: relative; display: inline-block; }
+.chart [data-x], .chart [data-value] { cursor: crosshair; }
+.chart .dimmed { opacity: 0.25; }
+.chart-tooltip {
+  position: absolute;
+  display: none;
+  pointer-events: none;
+  padding: 4px 8px;
+  border-radius: 3px;
+  background: rgba(51, 51, 51, 0.9);
+  color: #fff;
+  font-size: 12px;
+  line-height: 1.4;
+  white-space: nowrap;
+}
+"#;
+
+/// The script that shows the values of the hovered datum in a tooltip and
+/// highlights the series it belongs to.
+/// The values are read from the `data-*` attributes of the chart elements.
+const SCRIPT: &str = r#"
+(function () {
+  var chart = document.querySelector(".chart");
+  var tooltip = chart.querySelector(".chart-tooltip");
+  var fields = ["key", "category", "x", "y", "value"];
+  var series = Array.prototype.filter.call(chart.querySelectorAll("[data-key]"), function (element) {
+    return element.parentNode.closest("[data-key]") === null;
+  });
+
+  function highlight(key) {
+    series.forEach(function (element) {
+      element.classList.toggle("dimmed", key !== null && element.dataset.key !== key);
+    });
+  }
+
+  chart.addEventListener("mousemove", function (event) {
+    var keyed = event.target.closest("[data-key]");
+    highlight(keyed ? keyed.dataset.key : null);
+
+    var datum = event.target.closest("[data-x], [data-value]");
+    if (!datum) {
+      tooltip.style.display = "none";
+      return;
+    }
+
+    tooltip.textContent = "";
+    fields.forEach(function (field) {
+      if (datum.dataset[field] !== undefined) {
+        var line = document.createElement("div");
+        line.textContent = field + ": " + datum.dataset[field];
+        tooltip.appendChild(line);
+      }
+    });
+    var bounds = chart.getBoundingClientRect();
+    tooltip.style.left = (event.clientX - bounds.left + 12) + "px";
+    tooltip.style.top = (event.clientY - bounds.top + 12) + "px";
+    tooltip.style.display = "block";
+  });
+
+  chart.addEventListener("mouseleave", function () {
+    highlight(null);
+    tooltip.style.display = "none";
+  });
+})();
+"#;
+
+/// Wrap an SVG document into a self-contained HTML page that shows the
+/// values of the hovered chart elements in a tooltip.
+pub(crate) fn svg_to_html(svg: &str, title: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<div class=\"chart\">\n{}\n<div class=\"chart-tooltip\"></div>\n</div>\n<script>{}</script>\n</body>\n</html>\n",
+        escape_html(title),
+        STYLE,
+        svg,
+        SCRIPT
+    )
+}
+
+/// Escape the characters that have a special meaning in HTML text.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_inlines_svg_and_escapes_title() {
+        let html = svg_to_html("<svg></svg>", "Sales <2020>");
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Sales &lt;2020&gt;</title>"));
+        assert!(html.contains("<svg></svg>"));
+        assert!(html.contains("<script>"));
+    }
+}
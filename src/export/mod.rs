@@ -0,0 +1,3 @@
+pub(crate) mod html;
+pub(crate) mod pdf;
+pub(crate) mod png;
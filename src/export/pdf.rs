@@ -0,0 +1,328 @@
+use crate::error::ChartError;
+use pdf_writer::types::{LineCapStyle, LineJoinStyle};
+use pdf_writer::{Content, Finish, Name, Pdf, Rect, Ref, Str};
+use resvg::tiny_skia::{PathSegment, Point, Transform};
+use resvg::usvg::{
+    fontdb, FillRule, Group, LineCap, LineJoin, Node, Options, Paint, PostProcessingSteps,
+    TextAnchor, Tree, TreeParsing, TreePostProc, Visibility,
+};
+
+const CATALOG_ID: Ref = Ref::new(1);
+const PAGE_TREE_ID: Ref = Ref::new(2);
+const PAGE_ID: Ref = Ref::new(3);
+const CONTENT_ID: Ref = Ref::new(4);
+const FONT_ID: Ref = Ref::new(5);
+const FONT_NAME: Name<'static> = Name(b"F1");
+
+/// Advance widths of the printable ASCII characters (from space to tilde)
+/// in Helvetica, in thousandths of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+
+/// Convert an SVG document into a single page vector PDF.
+/// Shapes are written as PDF paths and text is set in Helvetica, one of the
+/// standard fonts every PDF reader provides, so no font has to be embedded.
+pub(crate) fn svg_to_pdf(svg: &str) -> Result<Vec<u8>, ChartError> {
+    let mut tree = Tree::from_str(svg, &Options::default())
+        .map_err(|e| ChartError::Render(format!("Could not parse the chart SVG: {}", e)))?;
+
+    // Only the absolute transforms are needed, text stays as text.
+    let steps = PostProcessingSteps {
+        convert_text_into_paths: false,
+    };
+    tree.postprocess(steps, &fontdb::Database::new());
+
+    let width = tree.size.width();
+    let height = tree.size.height();
+    let mut writer = PageWriter {
+        content: Content::new(),
+        alphas: Vec::new(),
+    };
+
+    // SVG has its origin in the top left corner while PDF has it in the bottom left.
+    writer
+        .content
+        .transform([1_f32, 0_f32, 0_f32, -1_f32, 0_f32, height]);
+    writer.write_group(&tree.root, 1_f32);
+
+    let mut pdf = Pdf::new();
+    pdf.catalog(CATALOG_ID).pages(PAGE_TREE_ID);
+    pdf.pages(PAGE_TREE_ID).kids([PAGE_ID]).count(1);
+
+    let mut page = pdf.page(PAGE_ID);
+    page.media_box(Rect::new(0_f32, 0_f32, width, height))
+        .parent(PAGE_TREE_ID)
+        .contents(CONTENT_ID);
+    let mut resources = page.resources();
+    resources.fonts().pair(FONT_NAME, FONT_ID);
+    let mut states = resources.ext_g_states();
+    for (i, _) in writer.alphas.iter().enumerate() {
+        states.pair(Name(alpha_name(i).as_bytes()), alpha_ref(i));
+    }
+    states.finish();
+    resources.finish();
+    page.finish();
+
+    pdf.type1_font(FONT_ID)
+        .base_font(Name(b"Helvetica"))
+        .encoding_predefined(Name(b"WinAnsiEncoding"));
+    for (i, (fill_alpha, stroke_alpha)) in writer.alphas.iter().enumerate() {
+        pdf.ext_graphics(alpha_ref(i))
+            .non_stroking_alpha(*fill_alpha)
+            .stroking_alpha(*stroke_alpha);
+    }
+    pdf.stream(CONTENT_ID, &writer.content.finish());
+
+    Ok(pdf.finish())
+}
+
+fn alpha_name(index: usize) -> String {
+    format!("GS{}", index)
+}
+
+fn alpha_ref(index: usize) -> Ref {
+    Ref::new(FONT_ID.get() + 1 + index as i32)
+}
+
+fn to_matrix(transform: Transform) -> [f32; 6] {
+    [
+        transform.sx,
+        transform.ky,
+        transform.kx,
+        transform.sy,
+        transform.tx,
+        transform.ty,
+    ]
+}
+
+/// Width of a string set in Helvetica at the given font size.
+fn text_width(text: &str, font_size: f32) -> f32 {
+    let units: u32 = text
+        .chars()
+        .map(|c| match c {
+            ' '..='~' => HELVETICA_WIDTHS[c as usize - 32] as u32,
+            _ => 556,
+        })
+        .sum();
+
+    units as f32 * font_size / 1000_f32
+}
+
+/// Encode a string with the WinAnsi encoding of the standard fonts,
+/// replacing the characters it does not cover with a question mark.
+fn encode_win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c as u32 {
+            code @ 0x20..=0x7e | code @ 0xa0..=0xff => code as u8,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Accumulates the page content stream and the transparency states it uses.
+struct PageWriter {
+    content: Content,
+    alphas: Vec<(f32, f32)>,
+}
+
+impl PageWriter {
+    /// Select a graphics state with the given fill and stroke opacity.
+    fn set_alpha(&mut self, fill_alpha: f32, stroke_alpha: f32) {
+        if fill_alpha >= 1_f32 && stroke_alpha >= 1_f32 {
+            return;
+        }
+
+        let index = match self
+            .alphas
+            .iter()
+            .position(|alpha| *alpha == (fill_alpha, stroke_alpha))
+        {
+            Some(index) => index,
+            None => {
+                self.alphas.push((fill_alpha, stroke_alpha));
+                self.alphas.len() - 1
+            }
+        };
+        self.content
+            .set_parameters(Name(alpha_name(index).as_bytes()));
+    }
+
+    fn write_group(&mut self, group: &Group, opacity: f32) {
+        let opacity = opacity * group.opacity.get();
+
+        for node in group.children.iter() {
+            match node {
+                Node::Group(group) => self.write_group(group, opacity),
+                Node::Path(path) => self.write_path(path, opacity),
+                Node::Text(text) => self.write_text(text, opacity),
+                Node::Image(_) => {}
+            }
+        }
+    }
+
+    fn write_path(&mut self, path: &resvg::usvg::Path, opacity: f32) {
+        if path.visibility != Visibility::Visible || (path.fill.is_none() && path.stroke.is_none())
+        {
+            return;
+        }
+
+        self.content.save_state();
+        self.content.transform(to_matrix(path.abs_transform));
+
+        let mut fill_alpha = opacity;
+        if let Some(fill) = &path.fill {
+            let (r, g, b) = paint_rgb(&fill.paint);
+            self.content.set_fill_rgb(r, g, b);
+            fill_alpha *= fill.opacity.get();
+        }
+
+        let mut stroke_alpha = opacity;
+        if let Some(stroke) = &path.stroke {
+            let (r, g, b) = paint_rgb(&stroke.paint);
+            self.content.set_stroke_rgb(r, g, b);
+            self.content.set_line_width(stroke.width.get());
+            self.content.set_line_cap(match stroke.linecap {
+                LineCap::Butt => LineCapStyle::ButtCap,
+                LineCap::Round => LineCapStyle::RoundCap,
+                LineCap::Square => LineCapStyle::ProjectingSquareCap,
+            });
+            self.content.set_line_join(match stroke.linejoin {
+                LineJoin::Miter | LineJoin::MiterClip => LineJoinStyle::MiterJoin,
+                LineJoin::Round => LineJoinStyle::RoundJoin,
+                LineJoin::Bevel => LineJoinStyle::BevelJoin,
+            });
+            if let Some(dasharray) = &stroke.dasharray {
+                self.content
+                    .set_dash_pattern(dasharray.iter().copied(), stroke.dashoffset);
+            }
+            stroke_alpha *= stroke.opacity.get();
+        }
+        self.set_alpha(fill_alpha, stroke_alpha);
+
+        let mut last = Point::zero();
+        for segment in path.data.segments() {
+            match segment {
+                PathSegment::MoveTo(p) => {
+                    self.content.move_to(p.x, p.y);
+                    last = p;
+                }
+                PathSegment::LineTo(p) => {
+                    self.content.line_to(p.x, p.y);
+                    last = p;
+                }
+                PathSegment::QuadTo(p1, p) => {
+                    // PDF only knows cubic curves, so the control point is elevated.
+                    let c1 = Point::from_xy(
+                        last.x + (p1.x - last.x) * 2_f32 / 3_f32,
+                        last.y + (p1.y - last.y) * 2_f32 / 3_f32,
+                    );
+                    let c2 = Point::from_xy(
+                        p.x + (p1.x - p.x) * 2_f32 / 3_f32,
+                        p.y + (p1.y - p.y) * 2_f32 / 3_f32,
+                    );
+                    self.content.cubic_to(c1.x, c1.y, c2.x, c2.y, p.x, p.y);
+                    last = p;
+                }
+                PathSegment::CubicTo(p1, p2, p) => {
+                    self.content.cubic_to(p1.x, p1.y, p2.x, p2.y, p.x, p.y);
+                    last = p;
+                }
+                PathSegment::Close => {
+                    self.content.close_path();
+                }
+            }
+        }
+
+        let even_odd = matches!(&path.fill, Some(fill) if fill.rule == FillRule::EvenOdd);
+        match (path.fill.is_some(), path.stroke.is_some(), even_odd) {
+            (true, true, true) => self.content.fill_even_odd_and_stroke(),
+            (true, true, false) => self.content.fill_nonzero_and_stroke(),
+            (true, false, true) => self.content.fill_even_odd(),
+            (true, false, false) => self.content.fill_nonzero(),
+            _ => self.content.stroke(),
+        };
+        self.content.restore_state();
+    }
+
+    fn write_text(&mut self, text: &resvg::usvg::Text, opacity: f32) {
+        self.content.save_state();
+        self.content.transform(to_matrix(text.abs_transform));
+
+        let mut char_index = 0;
+        for chunk in text.chunks.iter() {
+            let span = match chunk.spans.first() {
+                Some(span) => span,
+                None => continue,
+            };
+            let font_size = span.font_size.get();
+            let width = text_width(&chunk.text, font_size);
+            let dx = text.dx.get(char_index).copied().unwrap_or(0_f32);
+            let dy = text.dy.get(char_index).copied().unwrap_or(0_f32);
+            let anchor_offset = match chunk.anchor {
+                TextAnchor::Start => 0_f32,
+                TextAnchor::Middle => width / 2_f32,
+                TextAnchor::End => width,
+            };
+            let x = chunk.x.unwrap_or(0_f32) + dx - anchor_offset;
+            let y = chunk.y.unwrap_or(0_f32) + dy;
+            char_index += chunk.text.chars().count();
+
+            let fill = match &span.fill {
+                Some(fill) => fill,
+                None => continue,
+            };
+            let (r, g, b) = paint_rgb(&fill.paint);
+            self.content.set_fill_rgb(r, g, b);
+            self.set_alpha(opacity * fill.opacity.get(), 1_f32);
+
+            // The text matrix flips the glyphs back upright inside the flipped page.
+            self.content
+                .begin_text()
+                .set_font(FONT_NAME, font_size)
+                .set_text_matrix([1_f32, 0_f32, 0_f32, -1_f32, x, y])
+                .show(Str(&encode_win_ansi(&chunk.text)))
+                .end_text();
+        }
+
+        self.content.restore_state();
+    }
+}
+
+/// Gradients and patterns are not used by the charts, they fall back to black.
+fn paint_rgb(paint: &Paint) -> (f32, f32, f32) {
+    match paint {
+        Paint::Color(color) => (
+            color.red as f32 / 255_f32,
+            color.green as f32 / 255_f32,
+            color.blue as f32 / 255_f32,
+        ),
+        _ => (0_f32, 0_f32, 0_f32),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pdf_contains_paths_and_text() {
+        let svg = r##"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="30" viewBox="0 0 40 30"><rect width="10" height="10" fill="#ff0000" fill-opacity="0.5"/><text x="20" y="20" font-size="12">Label</text></svg>"##;
+        let pdf = svg_to_pdf(svg).unwrap();
+        let content = String::from_utf8_lossy(&pdf);
+
+        assert!(content.starts_with("%PDF"));
+        assert!(content.contains("/MediaBox [0 0 40 30]"));
+        assert!(content.contains("(Label) Tj"));
+        assert!(content.contains("/ca 0.5"));
+    }
+
+    #[test]
+    fn text_width_uses_helvetica_metrics() {
+        assert_eq!(text_width("Il", 10_f32), 5_f32);
+    }
+}
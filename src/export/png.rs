@@ -0,0 +1,68 @@
+use crate::error::ChartError;
+use resvg::tiny_skia::{Color, Pixmap, Transform};
+use resvg::usvg::{fontdb, Options, PostProcessingSteps, Tree, TreeParsing, TreePostProc};
+
+/// Rasterize an SVG document into PNG bytes.
+/// Every SVG unit is rendered as `scale` pixels, so a scale of 2 produces
+/// an image twice as wide and twice as tall as the chart.
+pub(crate) fn svg_to_png(svg: &str, scale: f32) -> Result<Vec<u8>, ChartError> {
+    if !(scale.is_finite() && scale > 0_f32) {
+        return Err(ChartError::Render(format!(
+            "The raster scale should be a positive number, got {}.",
+            scale
+        )));
+    }
+
+    let mut tree = Tree::from_str(svg, &Options::default())
+        .map_err(|e| ChartError::Render(format!("Could not parse the chart SVG: {}", e)))?;
+
+    // Text is converted to paths using whatever fonts the system provides.
+    let mut fonts = fontdb::Database::new();
+    fonts.load_system_fonts();
+    tree.postprocess(PostProcessingSteps::default(), &fonts);
+
+    let width = (tree.size.width() * scale).ceil() as u32;
+    let height = (tree.size.height() * scale).ceil() as u32;
+    let mut pixmap = Pixmap::new(width, height).ok_or_else(|| {
+        ChartError::Render(format!("Cannot create a {}x{} image.", width, height))
+    })?;
+    pixmap.fill(Color::WHITE);
+
+    resvg::render(
+        &tree,
+        Transform::from_scale(scale, scale),
+        &mut pixmap.as_mut(),
+    );
+
+    pixmap
+        .encode_png()
+        .map_err(|e| ChartError::Render(format!("Could not encode the chart as PNG: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn png_is_scaled() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="30" viewBox="0 0 40 30"><rect width="10" height="10" fill="red"/></svg>"#;
+        let image = svg_to_png(svg, 2_f32).unwrap();
+
+        assert_eq!(&image[1..4], b"PNG");
+        // The IHDR chunk stores the width and height as big-endian integers.
+        assert_eq!(
+            u32::from_be_bytes([image[16], image[17], image[18], image[19]]),
+            80
+        );
+        assert_eq!(
+            u32::from_be_bytes([image[20], image[21], image[22], image[23]]),
+            60
+        );
+    }
+
+    #[test]
+    fn png_rejects_invalid_scale() {
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="30"></svg>"#;
+        assert!(svg_to_png(svg, 0_f32).is_err());
+    }
+}
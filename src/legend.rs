@@ -1,5 +1,4 @@
-use svg::node::element::Group;
-use svg::Node;
+use crate::backend::{Backend, GroupOptions};
 use crate::components::legend::LegendEntry;
 
 pub(crate) struct Legend {
@@ -16,10 +15,14 @@ impl Legend {
         }
     }
 
-    pub fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new().set("class", "g-legend");
+    /// Draw the legend on the given backend, with its top left corner at the given position.
+    pub fn draw(&self, backend: &mut dyn Backend, position: (f32, f32)) {
+        backend.begin_group(&GroupOptions::new().set_class("g-legend").set_translate(position.0, position.1));
         let max_entry_length = match self.entries.iter().map(|entry| entry.get_width()).max() {
-            None => return Ok(group),
+            None => {
+                backend.end_group();
+                return;
+            },
             Some(len) => len,
         };
         let gap_between_legend_entries = 10;
@@ -33,13 +36,11 @@ impl Legend {
                 current_row_offset += 1;
             }
 
-            let mut entry_group = entry.to_svg()?;
-            entry_group.assign("transform", format!("translate({},{})", acc_row_width, current_row_offset * legend_row_height));
-            group.append(entry_group);
+            entry.draw(backend, (acc_row_width as f32, (current_row_offset * legend_row_height) as f32));
 
             acc_row_width += max_entry_length + gap_between_legend_entries;
         }
 
-        Ok(group)
+        backend.end_group();
     }
-}
\ No newline at end of file
+}
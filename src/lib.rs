@@ -25,22 +25,48 @@
 mod chart;
 // mod view;
 mod axis;
+mod backend;
 mod colors;
 mod components;
+mod error;
+mod export;
 mod legend;
 mod scales;
+#[cfg(feature = "serde")]
+mod spec;
 mod views;
 
 pub use crate::axis::{Axis, AxisPosition};
+pub use crate::backend::svg::SvgBackend;
+pub use crate::backend::terminal::TerminalBackend;
+pub use crate::backend::{Backend, GroupOptions, ShapeStyle, TextAnchor, TextStyle};
 pub use crate::chart::Chart;
 pub use crate::colors::Color;
+pub use crate::components::axis::Gridlines;
 pub use crate::components::bar::BarLabelPosition;
+pub use crate::error::ChartError;
 pub use crate::components::line::LineSeries;
 pub use crate::components::scatter::{MarkerType, PointLabelPosition};
 pub use crate::scales::band::ScaleBand;
+pub use crate::scales::color::ScaleColor;
 pub use crate::scales::linear::ScaleLinear;
 pub use crate::scales::logarithmic::ScaleLogarithmic;
-pub use crate::scales::Scale;
+pub use crate::scales::nested_band::ScaleNestedBand;
+pub use crate::scales::ordinal::ScaleOrdinal;
+pub use crate::scales::point::ScalePoint;
+pub use crate::scales::pow::{ScalePow, ScaleSqrt};
+pub use crate::scales::quantile::ScaleQuantile;
+pub use crate::scales::quantize::ScaleQuantize;
+pub use crate::scales::symlog::ScaleSymlog;
+pub use crate::scales::threshold::ScaleThreshold;
+#[cfg(feature = "chrono")]
+pub use crate::scales::time::ScaleTime;
+pub use crate::scales::{Scale, ScaleNumber};
+#[cfg(feature = "serde")]
+pub use crate::spec::{
+    AxisSpec, BarViewSpec, ChartSpec, CsvSpec, DataSpec, DatumSpec, MarginsSpec, PointViewSpec,
+    ScaleSpec, ValueSpec, ViewSpec,
+};
 pub use crate::views::area::AreaSeriesView;
 pub use crate::views::datum::{BarDatum, PointDatum};
 pub use crate::views::horizontal_bar::HorizontalBarView;
@@ -1,3 +1,4 @@
+use crate::error::ChartError;
 use crate::scales::{Scale, ScaleType};
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -185,8 +186,21 @@ impl Scale<String> for ScaleBand {
     }
 
     /// Get the range value for the given domain entry.
-    fn scale(&self, domain: &String) -> f32 {
-        self.offsets[*self.index.get(domain).unwrap()]
+    fn scale(&self, domain: &String) -> Result<f32, ChartError> {
+        match self.index.get(domain) {
+            Some(index) => Ok(self.offsets[*index]),
+            None => Err(ChartError::Scale(format!(
+                "The category \"{}\" is not part of the band scale domain.",
+                domain
+            ))),
+        }
+    }
+
+    /// Get the category whose band center is the closest to the given range value.
+    fn invert(&self, range: f32) -> Result<String, ChartError> {
+        nearest_band(&self.domain, &self.offsets, self.bandwidth, range)
+            .cloned()
+            .ok_or_else(|| ChartError::Scale(String::from("The band scale domain is empty.")))
     }
 
     /// Get the bandwidth (if present).
@@ -209,3 +223,54 @@ impl Scale<String> for ScaleBand {
         self.domain.clone()
     }
 }
+
+/// Return the category whose band center is the closest to the given range value.
+pub(crate) fn nearest_band<'a>(
+    domain: &'a [String],
+    offsets: &[f32],
+    bandwidth: f32,
+    range: f32,
+) -> Option<&'a String> {
+    let center = bandwidth / 2_f32;
+    domain
+        .iter()
+        .zip(offsets.iter())
+        .min_by(|(_, a), (_, b)| {
+            (*a + center - range)
+                .abs()
+                .total_cmp(&(*b + center - range).abs())
+        })
+        .map(|(category, _)| category)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scale_unknown_category() {
+        let scale = ScaleBand::new()
+            .set_domain(vec![String::from("A"), String::from("B")])
+            .set_range(vec![0, 100]);
+
+        assert!(scale.scale(&String::from("A")).is_ok());
+        assert!(matches!(
+            scale.scale(&String::from("C")),
+            Err(ChartError::Scale(_))
+        ));
+    }
+
+    #[test]
+    fn invert_finds_nearest_band() {
+        let scale = ScaleBand::new()
+            .set_domain(vec![String::from("A"), String::from("B")])
+            .set_range(vec![0, 100])
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32);
+
+        assert_eq!(scale.invert(10_f32).unwrap(), "A");
+        assert_eq!(scale.invert(60_f32).unwrap(), "B");
+        assert_eq!(scale.invert(250_f32).unwrap(), "B");
+        assert!(ScaleBand::new().invert(0_f32).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::colors::Color;
+use crate::error::ChartError;
+
+/// The scale to map a continuous domain to colors by interpolating through a palette.
+/// A domain of a start and an end makes a sequential scale, where the palette runs from the start
+/// to the end. A domain of a start, a midpoint and an end makes a diverging scale, where the first
+/// half of the palette runs up to the midpoint and the second half away from it.
+/// Values outside of the domain get the first or the last color of the palette.
+#[derive(Debug)]
+pub struct ScaleColor {
+    /// The domain limits of the dataset that the scale is going to represent.
+    domain: Vec<f32>,
+    /// The colors the domain is interpolated through, in order.
+    palette: Vec<Color>,
+}
+
+impl Default for ScaleColor {
+    fn default() -> Self {
+        Self {
+            domain: vec![0., 1.],
+            palette: Color::color_scheme_viridis(),
+        }
+    }
+}
+
+impl ScaleColor {
+    /// Create a new color scale with default values, mapping `[0, 1]` through viridis.
+    pub fn new() -> Self {
+        ScaleColor::default()
+    }
+
+    /// Set the domain limits for the scale, either a start and an end, or a start,
+    /// a midpoint and an end for a diverging scale.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.domain = range;
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        &self.domain
+    }
+
+    /// Set the colors the domain is interpolated through, e.g. `Color::color_scheme_blues()`.
+    pub fn set_palette(mut self, palette: Vec<Color>) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Get the colors the domain is interpolated through.
+    pub fn palette(&self) -> &Vec<Color> {
+        &self.palette
+    }
+
+    /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
+    fn normalize(a: f32, b: f32, x: f32) -> f32 {
+        // If a == b then return 0.5
+        if (a - b).abs() < f32::EPSILON {
+            0.5
+        } else {
+            ((x - a) / (b - a)).clamp(0_f32, 1_f32)
+        }
+    }
+
+    /// Get the color for the given domain value.
+    pub fn scale(&self, value: f32) -> Result<Color, ChartError> {
+        if self.palette.is_empty() || value.is_nan() {
+            return Err(ChartError::Scale(format!(
+                "{} cannot be mapped by a color scale with {} colors",
+                value,
+                self.palette.len()
+            )));
+        }
+
+        let t = match self.domain.as_slice() {
+            [start, end] => Self::normalize(*start, *end, value),
+            [start, midpoint, end] => {
+                let below = (value - midpoint) * (start - midpoint) > 0_f32;
+                if below {
+                    Self::normalize(*start, *midpoint, value) / 2_f32
+                } else {
+                    0.5 + Self::normalize(*midpoint, *end, value) / 2_f32
+                }
+            }
+            _ => {
+                return Err(ChartError::Scale(format!(
+                    "a color scale domain needs a start and an end, and optionally a midpoint, got {} values",
+                    self.domain.len()
+                )))
+            }
+        };
+
+        let position = t * (self.palette.len() - 1) as f32;
+        let index = (position.floor() as usize).min(self.palette.len() - 1);
+        let next = (index + 1).min(self.palette.len() - 1);
+        Ok(self.palette[index].interpolate(&self.palette[next], position - index as f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequential_and_diverging_palettes() {
+        let scale = ScaleColor::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_palette(Color::from_vec_of_hex_strings(vec!["#000000", "#ffffff"]));
+
+        assert_eq!(scale.scale(0_f32).unwrap().as_hex(), "#000000");
+        assert_eq!(scale.scale(50_f32).unwrap().as_hex(), "#808080");
+        assert_eq!(scale.scale(150_f32).unwrap().as_hex(), "#ffffff");
+
+        let scale = ScaleColor::new()
+            .set_domain(vec![-1_f32, 0_f32, 10_f32])
+            .set_palette(Color::from_vec_of_hex_strings(vec![
+                "#ff0000", "#ffffff", "#0000ff",
+            ]));
+
+        assert_eq!(scale.scale(-0.5_f32).unwrap().as_hex(), "#ff8080");
+        assert_eq!(scale.scale(0_f32).unwrap().as_hex(), "#ffffff");
+        assert_eq!(scale.scale(5_f32).unwrap().as_hex(), "#8080ff");
+        assert!(scale.scale(f32::NAN).is_err());
+    }
+}
@@ -1,44 +1,204 @@
-use crate::scales::{Scale, ScaleType};
+use crate::error::ChartError;
+use crate::scales::{ticks_within_domain, Scale, ScaleNumber, ScaleType};
 use std::cmp::{max, Ordering};
 
 /// The scale to represent linear data.
+/// The domain values are `f32` by default, and can be `f64` when more precision is needed.
 #[derive(Debug)]
-pub struct ScaleLinear {
+pub struct ScaleLinear<T = f32> {
+    /// The domain limits as they were set, before padding and rounding.
+    data_domain: Vec<T>,
     /// The domain limits of the dataset that the scale is going to represent.
-    domain: Vec<f32>,
+    domain: Vec<T>,
     /// The range limits of the drawable area on the chart.
     range: Vec<isize>,
     /// The amount of ticks to display.
     tick_count: usize,
+    /// The values to display ticks at, computed from the tick count when not set.
+    tick_values: Option<Vec<T>>,
+    /// The fraction of the domain span added before the start and after the end of the domain.
+    padding: f32,
+    /// Whether to extend the domain to the closest round tick values.
+    nice: bool,
+    /// Whether to pin the values outside of the domain to the range limits.
+    clamp: bool,
+    /// The domain intervals that are left out of the range.
+    breaks: Vec<(T, T)>,
 }
 
-impl Default for ScaleLinear {
+impl<T> Default for ScaleLinear<T> {
     fn default() -> Self {
         Self {
+            data_domain: Vec::new(),
             domain: Vec::new(),
             range: vec![0, 1],
             tick_count: 10,
+            tick_values: None,
+            padding: 0_f32,
+            nice: false,
+            clamp: false,
+            breaks: Vec::new(),
         }
     }
 }
 
-impl ScaleLinear {
+impl<T: ScaleNumber> ScaleLinear<T> {
     /// Create a new linear scale with default values.
     pub fn new() -> Self {
         ScaleLinear::default()
     }
 
     /// Set the domain limits for the scale band.
-    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
-        self.domain = range;
+    pub fn set_domain(mut self, range: Vec<T>) -> Self {
+        self.data_domain = range;
+        self.rescale();
         self
     }
 
-    /// Get the domain limits of the scale.
-    pub fn domain(&self) -> &Vec<f32> {
+    /// Get the domain limits of the scale, including the padding and the rounding.
+    pub fn domain(&self) -> &Vec<T> {
         &self.domain
     }
 
+    /// Extend the domain to the closest round tick values, so the axis starts and ends on a tick.
+    pub fn nice(mut self) -> Self {
+        self.nice = true;
+        self.rescale();
+        self
+    }
+
+    /// Set whether values outside of the domain are pinned to the range limits.
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    /// Set the fraction of the domain span that is added before its start and after its end,
+    /// e.g. `0.05` leaves 5% of free space on each side of the data.
+    pub fn set_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self.rescale();
+        self
+    }
+
+    /// Set the approximate amount of ticks to display, 10 by default.
+    /// The ticks are still placed on round values, so the actual amount may differ slightly.
+    /// A nice domain is rounded to the tick step of this amount, and is left as it is without ticks.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self.rescale();
+        self
+    }
+
+    /// Set the exact values to display ticks at instead of computing them.
+    /// Values outside of the domain are not displayed.
+    pub fn set_tick_values(mut self, tick_values: Vec<T>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    /// Set the domain intervals to leave out of the range, e.g. the gap between most of the
+    /// data and an outlier. Their start and end are mapped to the same range value, where axes
+    /// draw a break marker, and they hold no ticks.
+    pub fn set_breaks(mut self, breaks: Vec<(T, T)>) -> Self {
+        self.breaks = breaks;
+        self
+    }
+
+    /// Get the domain intervals that are left out of the range.
+    pub fn breaks(&self) -> &Vec<(T, T)> {
+        &self.breaks
+    }
+
+    /// Return the breaks within the domain in ascending order, with the overlapping ones merged.
+    fn domain_breaks(&self) -> Vec<(f64, f64)> {
+        if self.domain.len() != 2 {
+            return Vec::new();
+        }
+        let (a, b) = (self.domain[0].to_f64(), self.domain[1].to_f64());
+        let (lower, upper) = (a.min(b), a.max(b));
+
+        let mut breaks = self
+            .breaks
+            .iter()
+            .map(|(start, end)| {
+                let (start, end) = (start.to_f64(), end.to_f64());
+                (start.min(end).max(lower), start.max(end).min(upper))
+            })
+            .filter(|(start, end)| start < end)
+            .collect::<Vec<(f64, f64)>>();
+        breaks.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut merged: Vec<(f64, f64)> = Vec::new();
+        for (start, end) in breaks {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+        merged
+    }
+
+    /// Remove the breaks below a domain value from it, so that the breaks take no space.
+    fn collapse(breaks: &[(f64, f64)], x: f64) -> f64 {
+        breaks
+            .iter()
+            .map(|(start, end)| (x.min(*end) - start).max(0_f64))
+            .fold(x, |x, skipped| x - skipped)
+    }
+
+    /// Reverse `collapse`, values at a break are mapped to its start.
+    fn expand(breaks: &[(f64, f64)], x: f64) -> f64 {
+        breaks.iter().fold(
+            x,
+            |x, (start, end)| {
+                if x > *start {
+                    x + end - start
+                } else {
+                    x
+                }
+            },
+        )
+    }
+
+    /// Compute the domain from the data domain, the padding and the rounding.
+    fn rescale(&mut self) {
+        self.domain = self.data_domain.clone();
+        if self.domain.len() != 2 {
+            return;
+        }
+
+        let (a, b) = (self.domain[0].to_f64(), self.domain[1].to_f64());
+        let reverse = b < a;
+        let (mut start, mut stop) = if reverse { (b, a) } else { (a, b) };
+
+        let padding = (stop - start) * self.padding as f64;
+        start -= padding;
+        stop += padding;
+
+        // The tick step depends on the domain, so a second pass rounds to the final step.
+        for _ in 0..2 {
+            if !self.nice || stop <= start || self.tick_count == 0 {
+                break;
+            }
+            let step = self.tick_step(start, stop, self.tick_count);
+            if step > 0_f64 {
+                start = (start / step).floor() * step;
+                stop = (stop / step).ceil() * step;
+            } else if step < 0_f64 {
+                start = (start * -step).floor() / -step;
+                stop = (stop * -step).ceil() / -step;
+            }
+        }
+
+        let (start, stop) = (T::from_f64(start), T::from_f64(stop));
+        self.domain = if reverse {
+            vec![stop, start]
+        } else {
+            vec![start, stop]
+        };
+    }
+
     /// Set the range limits for the scale band.
     pub fn set_range(mut self, range: Vec<isize>) -> Self {
         self.range = range;
@@ -51,9 +211,9 @@ impl ScaleLinear {
     }
 
     /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
-    fn normalize(&self, a: f32, b: f32, x: f32) -> f32 {
+    fn normalize(&self, a: f64, b: f64, x: f64) -> f64 {
         // If a == b then return 0.5
-        if (a - b).abs() < f32::EPSILON {
+        if (a - b).abs() < f64::EPSILON {
             0.5
         } else {
             let b = b - a;
@@ -62,18 +222,18 @@ impl ScaleLinear {
     }
 
     /// Takes a value t in [0, 1] and returns the corresponding range in [a, b].
-    fn interpolate(&self, a: f32, b: f32, t: f32) -> f32 {
+    fn interpolate(&self, a: f64, b: f64, t: f64) -> f64 {
         (b - a) * t + a
     }
 
     /// Compute the distance between the ticks.
-    fn tick_step(&self, start: f32, stop: f32) -> f32 {
-        let e10 = 50_f32.sqrt();
-        let e5 = 10_f32.sqrt();
-        let e2 = 2_f32.sqrt();
-        let step = (stop - start) / max(0, self.tick_count) as f32;
-        let power = (step.ln() / 10_f32.ln()).trunc() as i32;
-        let error = step / 10_f32.powi(power);
+    fn tick_step(&self, start: f64, stop: f64, tick_count: usize) -> f64 {
+        let e10 = 50_f64.sqrt();
+        let e5 = 10_f64.sqrt();
+        let e2 = 2_f64.sqrt();
+        let step = (stop - start) / max(0, tick_count) as f64;
+        let power = (step.ln() / 10_f64.ln()).trunc() as i32;
+        let error = step / 10_f64.powi(power);
         let dynamic = if error >= e10 {
             10
         } else if error >= e5 {
@@ -85,36 +245,95 @@ impl ScaleLinear {
         };
 
         match power.cmp(&0) {
-            Ordering::Less => -(10_f32.powi(-power)) / dynamic as f32,
-            _ => dynamic as f32 * 10_f32.powi(power),
+            Ordering::Less => -(10_f64.powi(-power)) / dynamic as f64,
+            _ => dynamic as f64 * 10_f64.powi(power),
         }
     }
+
+    /// Compute the ticks between a and b for the given amount of ticks.
+    fn ticks_between(&self, a: f64, b: f64, tick_count: usize) -> Vec<T> {
+        let mut ticks: Vec<T> = Vec::new();
+
+        if (a - b).abs() < f64::EPSILON {
+            ticks.push(T::from_f64(a));
+            return ticks;
+        }
+
+        let step = self.tick_step(a, b, tick_count);
+        let mut i = 0;
+        if step > 0_f64 {
+            let start = (a / step).ceil();
+            let stop = (b / step).floor();
+            let nr_of_ticks = (stop - start + 1_f64).ceil() as i64;
+            while i < nr_of_ticks {
+                ticks.push(T::from_f64((start + i as f64) * step));
+                i += 1;
+            }
+        } else {
+            let start = (a * step).floor();
+            let stop = (b * step).ceil();
+            let nr_of_ticks = (start - stop + 1_f64).ceil() as i64;
+            while i < nr_of_ticks {
+                ticks.push(T::from_f64((start - i as f64) / step));
+                i += 1;
+            }
+        }
+
+        ticks
+    }
 }
 
-impl Scale<f32> for ScaleLinear {
+impl<T: ScaleNumber> Scale<T> for ScaleLinear<T> {
     /// Get the type of the scale.
     fn get_type(&self) -> ScaleType {
         ScaleType::Linear
     }
 
-    fn get_domain(&self) -> Vec<f32> {
+    fn get_domain(&self) -> Vec<T> {
         self.domain().clone()
     }
 
     /// Get the domain max of the scale.
     fn domain_max(&self) -> f32 {
-        self.domain[1]
+        self.domain[1].to_f64() as f32
     }
 
     /// Get the range value for the given domain entry.
-    fn scale(&self, domain: &f32) -> f32 {
-        let a = self.domain[0];
-        let b = self.domain[1];
-        let normalized = self.normalize(a, b, *domain);
-        let a = self.range[0] as f32;
-        let b = self.range[1] as f32;
+    fn scale(&self, domain: &T) -> Result<f32, ChartError> {
+        let a = self.domain[0].to_f64();
+        let b = self.domain[1].to_f64();
+        let domain = if self.clamp {
+            domain.to_f64().clamp(a.min(b), a.max(b))
+        } else {
+            domain.to_f64()
+        };
+        let breaks = self.domain_breaks();
+        let normalized = self.normalize(
+            Self::collapse(&breaks, a),
+            Self::collapse(&breaks, b),
+            Self::collapse(&breaks, domain),
+        );
+        let a = self.range[0] as f64;
+        let b = self.range[1] as f64;
+
+        Ok(self.interpolate(a, b, normalized) as f32)
+    }
+
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Result<T, ChartError> {
+        let mut normalized =
+            self.normalize(self.range[0] as f64, self.range[1] as f64, range as f64);
+        if self.clamp {
+            normalized = normalized.clamp(0_f64, 1_f64);
+        }
 
-        self.interpolate(a, b, normalized)
+        let breaks = self.domain_breaks();
+        let collapsed = self.interpolate(
+            Self::collapse(&breaks, self.domain[0].to_f64()),
+            Self::collapse(&breaks, self.domain[1].to_f64()),
+            normalized,
+        );
+        Ok(T::from_f64(Self::expand(&breaks, collapsed)))
     }
 
     /// Get the bandwidth (if present).
@@ -122,6 +341,14 @@ impl Scale<f32> for ScaleLinear {
         Some(0_f32)
     }
 
+    /// Get the range values of the breaks of the scale.
+    fn range_breaks(&self) -> Vec<f32> {
+        self.domain_breaks()
+            .iter()
+            .filter_map(|(start, _)| self.scale(&T::from_f64(*start)).ok())
+            .collect()
+    }
+
     /// Get the start range value.
     fn range_start(&self) -> f32 {
         self.range[0] as f32
@@ -133,34 +360,158 @@ impl Scale<f32> for ScaleLinear {
     }
 
     /// Get the list of ticks that represent the scale on a chart axis.
-    fn get_ticks(&self) -> Vec<f32> {
-        let mut ticks: Vec<f32> = Vec::new();
-
-        if (self.domain[0] - self.domain[1]).abs() < f32::EPSILON && self.tick_count > 0 {
-            ticks.push(self.domain[0]);
-            return ticks;
+    /// When the scale has breaks, each part of the domain between them gets its share of
+    /// the ticks according to its length.
+    fn get_ticks(&self) -> Vec<T> {
+        if let Some(values) = &self.tick_values {
+            let breaks = self.domain_breaks();
+            return ticks_within_domain(values, &self.domain)
+                .into_iter()
+                .filter(|value| {
+                    let value = value.to_f64();
+                    !breaks
+                        .iter()
+                        .any(|(start, end)| value > *start && value < *end)
+                })
+                .collect();
+        }
+        if self.tick_count == 0 {
+            return Vec::new();
+        }
+        let (a, b) = (self.domain[0].to_f64(), self.domain[1].to_f64());
+        let breaks = self.domain_breaks();
+        if breaks.is_empty() {
+            return self.ticks_between(a, b, self.tick_count);
         }
 
-        let step = self.tick_step(self.domain[0], self.domain[1]);
-        let mut i = 0;
-        if step > 0_f32 {
-            let start = (self.domain[0] / step).ceil();
-            let stop = (self.domain[1] / step).floor();
-            let nr_of_ticks = (stop - start + 1_f32).ceil() as i32;
-            while i < nr_of_ticks {
-                ticks.push((start + i as f32) * step);
-                i += 1;
-            }
-        } else {
-            let start = (self.domain[0] * step).floor();
-            let stop = (self.domain[1] * step).ceil();
-            let nr_of_ticks = (start - stop + 1_f32).ceil() as i32;
-            while i < nr_of_ticks {
-                ticks.push((start - i as f32) / step);
-                i += 1;
-            }
+        let (lower, upper) = (a.min(b), a.max(b));
+        let length = Self::collapse(&breaks, upper) - Self::collapse(&breaks, lower);
+        // A break over the whole domain leaves no span to share the ticks over.
+        if length <= 0_f64 {
+            return Vec::new();
         }
+        let mut bounds = vec![lower];
+        for (start, end) in breaks.iter() {
+            bounds.push(*start);
+            bounds.push(*end);
+        }
+        bounds.push(upper);
 
+        // Ticks on the edges of a break would overlap with the ones across the break.
+        let mut ticks = bounds
+            .chunks(2)
+            .flat_map(|part| {
+                let share = (part[1] - part[0]) / length * self.tick_count as f64;
+                self.ticks_between(part[0], part[1], (share.round() as usize).max(1))
+            })
+            .filter(|tick| {
+                let tick = tick.to_f64();
+                !breaks
+                    .iter()
+                    .any(|(start, end)| tick >= *start && tick <= *end)
+            })
+            .collect::<Vec<T>>();
+        if b < a {
+            ticks.reverse();
+        }
         ticks
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nice_extends_to_round_ticks() {
+        let scale = ScaleLinear::new().set_domain(vec![3_f32, 97.5_f32]).nice();
+        assert_eq!(scale.domain(), &vec![0_f32, 100_f32]);
+
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0.123_f32, 0.874_f32])
+            .nice();
+        assert_eq!(scale.domain(), &vec![0.1_f32, 0.9_f32]);
+
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_padding(0.1);
+        assert_eq!(scale.domain(), &vec![-10_f32, 110_f32]);
+
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 130_f32])
+            .nice()
+            .set_tick_count(5);
+        assert_eq!(scale.domain(), &vec![0_f32, 140_f32]);
+
+        let scale = ScaleLinear::new()
+            .set_domain(vec![3_f32, 97.5_f32])
+            .set_tick_count(0)
+            .nice();
+        assert_eq!(scale.domain(), &vec![3_f32, 97.5_f32]);
+    }
+
+    #[test]
+    fn clamp_pins_values_to_the_range() {
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 10_f32])
+            .set_range(vec![0, 100])
+            .clamp(true);
+
+        assert_eq!(scale.scale(&20_f32).unwrap(), 100_f32);
+        assert_eq!(scale.scale(&-5_f32).unwrap(), 0_f32);
+        assert_eq!(scale.invert(150_f32).unwrap(), 10_f32);
+    }
+
+    #[test]
+    fn tick_count_and_values() {
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_tick_count(5);
+        assert_eq!(
+            scale.get_ticks(),
+            vec![0_f32, 20_f32, 40_f32, 60_f32, 80_f32, 100_f32]
+        );
+
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_tick_values(vec![99.9_f32, 95_f32, 150_f32]);
+        assert_eq!(scale.get_ticks(), vec![99.9_f32, 95_f32]);
+    }
+
+    #[test]
+    fn f64_domain_keeps_precision() {
+        let start = 1_700_000_000_000_000_000_f64;
+        let scale = ScaleLinear::new()
+            .set_domain(vec![start, start + 1e9])
+            .set_range(vec![0, 1000])
+            .set_tick_count(2);
+
+        // The spacing of f32 values this large is wider than the whole domain.
+        assert!((scale.scale(&(start + 2.5e8)).unwrap() - 250_f32).abs() < 1e-3);
+        assert!((scale.invert(500_f32).unwrap() - (start + 5e8)).abs() <= 256_f64);
+        assert_eq!(scale.get_ticks().len(), 3);
+    }
+
+    #[test]
+    fn breaks_leave_out_domain_intervals() {
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 1000_f32])
+            .set_range(vec![0, 140])
+            .set_breaks(vec![(950_f32, 980_f32), (120_f32, 960_f32)]);
+
+        assert_eq!(scale.scale(&100_f32).unwrap(), 100_f32);
+        assert_eq!(scale.scale(&500_f32).unwrap(), 120_f32);
+        assert_eq!(scale.scale(&1000_f32).unwrap(), 140_f32);
+        assert_eq!(scale.invert(130_f32).unwrap(), 990_f32);
+        assert_eq!(scale.range_breaks(), vec![120_f32]);
+        assert!(scale
+            .get_ticks()
+            .iter()
+            .all(|tick| *tick < 120_f32 || *tick > 980_f32));
+
+        let scale = ScaleLinear::new()
+            .set_domain(vec![0_f32, 1000_f32])
+            .set_breaks(vec![(-10_f32, 1010_f32)]);
+        assert!(scale.get_ticks().is_empty());
+    }
+}
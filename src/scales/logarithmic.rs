@@ -1,49 +1,68 @@
 /// A logaritmic scale implementation
-use crate::scales::{Scale, ScaleType};
-use std::cmp::{max, Ordering};
+use crate::error::ChartError;
+use crate::scales::{scaled_power, ticks_within_domain, Scale, ScaleNumber, ScaleType};
 
 /// The scale to represent logarithmic data.
+/// The domain values are `f32` by default, and can be `f64` when more precision is needed.
 #[derive(Debug)]
-pub struct ScaleLogarithmic {
+pub struct ScaleLogarithmic<T = f32> {
+    /// The domain limits as they were set, before padding and rounding.
+    data_domain: Vec<T>,
     /// The domain limits of the dataset that the scale is going to represent.
-    domain: Vec<f32>,
+    domain: Vec<T>,
     /// The range limits of the drawable area on the chart.
     range: Vec<isize>,
+    /// The base of the logarithm.
+    base: f32,
     /// The amount of ticks to display.
     tick_count: usize,
+    /// The values to display ticks at, computed from the tick count when not set.
+    tick_values: Option<Vec<T>>,
+    /// Whether to add ticks at 2 and 5 times the powers of the base.
+    sub_ticks: bool,
+    /// The fraction of the logarithmic domain span added before the start and after the end of the domain.
+    padding: f32,
+    /// Whether to extend the domain to the closest powers of the base.
+    nice: bool,
+    /// Whether to pin the values outside of the domain to the range limits.
+    clamp: bool,
 }
 
-impl Default for ScaleLogarithmic {
+impl<T: ScaleNumber> Default for ScaleLogarithmic<T> {
     fn default() -> Self {
+        let domain = vec![T::from_f64(1.), T::from_f64(1_000.)];
         Self {
-            domain: vec![1., 1_000.],
+            data_domain: domain.clone(),
+            domain,
             range: vec![0, 1],
+            base: 10.,
             tick_count: 10,
+            tick_values: None,
+            sub_ticks: false,
+            padding: 0.,
+            nice: false,
+            clamp: false,
         }
     }
 }
 
-impl ScaleLogarithmic {
+impl<T: ScaleNumber> ScaleLogarithmic<T> {
     /// Create a new logarithmic scale with default values.
     pub fn new() -> Self {
         ScaleLogarithmic::default()
     }
 
-    /// Set the domain limits for the scale band.
-    /// The domain must be positive.
-    /// The domain must not contain NaN.
-    /// The domain must not contain infinite values.
-    /// The domain must not contain duplicate values.
-    /// The domain must not contain values that are not finite.
-    /// The domain must not contain values that are not normal.
-    /// The domain must not contain values that are not subnormal.
-    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
-        self.domain = range;
+    /// Set the domain limits for the scale.
+    /// Both limits must be strictly positive or strictly negative, a domain
+    /// that crosses or touches zero is rejected when values are scaled.
+    pub fn set_domain(mut self, range: Vec<T>) -> Self {
+        self.data_domain = range;
+        self.rescale();
         self
     }
 
-    /// Get the domain limits of the scale.
-    pub fn domain(&self) -> &Vec<f32> {
+    /// Get the domain limits of the scale, including the padding and the rounding.
+    pub fn domain(&self) -> &Vec<T> {
         &self.domain
     }
 
@@ -58,86 +77,221 @@ impl ScaleLogarithmic {
         &self.range
     }
 
-    /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
-    fn normalize(&self, domain_min: f32, domain_max: f32, x: f32) -> f32 {
-        // If a == b then return 0.5
-        if (domain_min - domain_max).abs() < f32::EPSILON {
-            0.5
-        } else {
-            let domain_distance = domain_max - domain_min;
-            (x - domain_min) / domain_distance
+    /// Set the base of the logarithm, 10 by default.
+    /// The base must be greater than 1, other bases are rejected when values are scaled.
+    pub fn set_base(mut self, base: f32) -> Self {
+        self.base = base;
+        self.rescale();
+        self
+    }
+
+    /// Get the base of the logarithm.
+    pub fn base(&self) -> f32 {
+        self.base
+    }
+
+    /// Set whether to add ticks at 2 and 5 times the powers of the base.
+    pub fn set_sub_ticks(mut self, sub_ticks: bool) -> Self {
+        self.sub_ticks = sub_ticks;
+        self
+    }
+
+    /// Extend the domain to the closest powers of the base, so the axis starts and ends on a tick.
+    pub fn nice(mut self) -> Self {
+        self.nice = true;
+        self.rescale();
+        self
+    }
+
+    /// Set whether values outside of the domain are pinned to the range limits.
+    /// Values of the wrong sign are pinned to the limit closest to zero.
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    /// Set the fraction of the domain span that is added before its start and after its end.
+    /// The span is measured in orders of magnitude, e.g. `0.1` over `[1, 1000]` adds 0.3 of an
+    /// order of magnitude on each side.
+    pub fn set_padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self.rescale();
+        self
+    }
+
+    /// Set the approximate amount of ticks to display, 10 by default.
+    /// The ticks are still placed on powers of the base, so the actual amount may differ slightly.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self.rescale();
+        self
+    }
+
+    /// Set the exact values to display ticks at instead of computing them.
+    /// Values outside of the domain are not displayed.
+    pub fn set_tick_values(mut self, tick_values: Vec<T>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    /// Compute the domain from the data domain, the padding and the rounding.
+    /// Domains that cannot be represented logarithmically are kept as they are.
+    fn rescale(&mut self) {
+        self.domain = self.data_domain.clone();
+        if self.check_domain().is_err() {
+            return;
+        }
+
+        let (a, b) = self.limits();
+        let sign = a.signum();
+        let (mut start, mut stop) = (self.log(a), self.log(b));
+        let padding = (stop - start) * self.padding as f64;
+        start -= padding;
+        stop += padding;
+
+        if self.nice {
+            if start <= stop {
+                start = start.floor();
+                stop = stop.ceil();
+            } else {
+                start = start.ceil();
+                stop = stop.floor();
+            }
+        }
+
+        let base = self.base as f64;
+        self.domain = vec![
+            T::from_f64(scaled_power(sign, base, start)),
+            T::from_f64(scaled_power(sign, base, stop)),
+        ];
+    }
+
+    /// Return the domain limits as `f64` values.
+    fn limits(&self) -> (f64, f64) {
+        (self.domain[0].to_f64(), self.domain[1].to_f64())
+    }
+
+    /// Check that the base and the domain can be represented logarithmically.
+    fn check_domain(&self) -> Result<(), ChartError> {
+        if !self.base.is_finite() || self.base <= 1_f32 {
+            return Err(ChartError::Scale(format!(
+                "the base of a logarithmic scale must be greater than 1, got {}",
+                self.base
+            )));
+        }
+        if self.domain.len() != 2 {
+            return Err(ChartError::Scale(format!(
+                "a logarithmic scale domain needs a start and an end, got {} values",
+                self.domain.len()
+            )));
+        }
+
+        let (start, end) = self.limits();
+        if !start.is_finite() || !end.is_finite() || start * end <= 0_f64 {
+            return Err(ChartError::Scale(format!(
+                "the logarithmic scale domain [{}, {}] crosses or touches zero, both limits must be strictly positive or strictly negative",
+                start, end
+            )));
         }
+        Ok(())
     }
 
-    /// Takes a value x in [0, 1] and returns the corresponding value in [a, b].
-    fn denormalize(&self, a: f32, b: f32, x: f32) -> f32 {
+    /// Return the logarithm of the magnitude of a value.
+    /// Negative domains are mirrored, so they are mapped like their absolute values.
+    fn log(&self, x: f64) -> f64 {
+        x.abs().ln() / (self.base as f64).ln()
+    }
+
+    /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
+    fn normalize(&self, domain_min: f64, domain_max: f64, x: f64) -> f64 {
         // If a == b then return 0.5
-        if (a - b).abs() < f32::EPSILON {
+        if (domain_min - domain_max).abs() < f64::EPSILON {
             0.5
         } else {
-            let b = b - a;
-            x * b + a
+            let domain_distance = domain_max - domain_min;
+            (x - domain_min) / domain_distance
         }
     }
 
     /// Takes a value t in [0, 1] and returns the corresponding range in [a, b].
-    fn interpolate(&self, a: f32, b: f32, t: f32) -> f32 {
+    fn interpolate(&self, a: f64, b: f64, t: f64) -> f64 {
         (b - a) * t + a
     }
 
-    /// Compute the distance between the ticks.
-    fn compute_tick_distance(&self) -> f32 {
-        let domain = self.domain();
-
-        let domain_min = domain[0];
-        let domain_max = domain[1];
-
-        let domain_distance = domain_max - domain_min;
-
-        let tick_count = self.tick_count;
-        let tick_distance = domain_distance / (tick_count as f32);
-
-        let tick_distance: f32 = tick_distance.log10().floor();
-        let mut tick_distance = 10_f32.powf(tick_distance);
-
-        let tick_count: f32 = domain_distance / tick_distance;
-        if tick_count < 2. {
-            tick_distance /= 2.;
-        } else if tick_count > 5. {
-            tick_distance *= 2.;
-        }
+    /// Return the multiples of the powers of the base between the given magnitudes.
+    /// Only every `step`-th power is used.
+    fn powers_between(&self, lower: f64, upper: f64, multiples: &[f64], step: i32) -> Vec<f64> {
+        let first = self.log(lower).floor() as i32;
+        let last = self.log(upper).ceil() as i32;
+        // Allow for the rounding errors of the powers at the domain limits.
+        let (lower, upper) = (lower * (1_f64 - 1e-6), upper * (1_f64 + 1e-6));
 
-        tick_distance
+        (first..=last)
+            .filter(|exponent| exponent.rem_euclid(step) == 0)
+            .flat_map(|exponent| {
+                let base = self.base as f64;
+                multiples
+                    .iter()
+                    .map(move |multiple| scaled_power(*multiple, base, exponent as f64))
+            })
+            .filter(|tick| *tick >= lower && *tick <= upper)
+            .collect()
     }
 }
 
-impl Scale<f32> for ScaleLogarithmic {
+impl<T: ScaleNumber> Scale<T> for ScaleLogarithmic<T> {
     /// Get the type of the scale
     fn get_type(&self) -> ScaleType {
         ScaleType::Logarithmic
     }
     /// Get the domain of the scale
-    fn get_domain(&self) -> Vec<f32> {
+    fn get_domain(&self) -> Vec<T> {
         self.domain().clone()
     }
 
     /// Get the domain max of the scale.
     fn domain_max(&self) -> f32 {
-        self.domain[1]
+        self.domain[1].to_f64() as f32
     }
 
     // Get the range value for the given domain entry
-    fn scale(&self, x: &f32) -> f32 {
-        let domain = self.domain();
+    fn scale(&self, x: &T) -> Result<f32, ChartError> {
+        self.check_domain()?;
+        let (a, b) = self.limits();
         let range = self.range();
+        let x = if self.clamp {
+            x.to_f64().clamp(a.min(b), a.max(b))
+        } else {
+            x.to_f64()
+        };
+
+        if x * a <= 0_f64 {
+            return Err(ChartError::Scale(format!(
+                "{} cannot be represented on a logarithmic scale over [{}, {}]",
+                x, self.domain[0], self.domain[1]
+            )));
+        }
+
+        let normalized = self.normalize(self.log(a), self.log(b), self.log(x));
+        Ok(self.interpolate(range[0] as f64, range[1] as f64, normalized) as f32)
+    }
 
-        let domain_min = domain[0];
-        let domain_max = domain[1];
-        let range_min = range[0];
-        let _range_max = range[1];
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Result<T, ChartError> {
+        self.check_domain()?;
+        let (a, b) = self.limits();
+        let mut normalized =
+            self.normalize(self.range[0] as f64, self.range[1] as f64, range as f64);
+        if self.clamp {
+            normalized = normalized.clamp(0_f64, 1_f64);
+        }
+        let exponent = self.interpolate(self.log(a), self.log(b), normalized);
 
-        let normalized = self.normalize(domain_min, domain_max, *x);
-        self.interpolate(range_min as f32, _range_max as f32, normalized)
+        Ok(T::from_f64(scaled_power(
+            a.signum(),
+            self.base as f64,
+            exponent,
+        )))
     }
 
     /// Get the bandwidth (if present)
@@ -156,27 +310,141 @@ impl Scale<f32> for ScaleLogarithmic {
     }
 
     /// Get the ticks for the scale.
-    fn get_ticks(&self) -> Vec<f32> {
-        let domain = self.domain();
-        let range = self.range();
+    /// The ticks land on the powers of the base, thinned out when the domain
+    /// spans more orders of magnitude than the tick count.
+    fn get_ticks(&self) -> Vec<T> {
+        if self.check_domain().is_err() {
+            return Vec::new();
+        }
+        if let Some(values) = &self.tick_values {
+            return ticks_within_domain(values, &self.domain);
+        }
+        if self.tick_count == 0 {
+            return Vec::new();
+        }
 
-        let domain_min = domain[0];
-        let domain_max = domain[1];
-        let range_min = range[0];
-        let range_max = range[1];
+        let (a, b) = self.limits();
+        let (start, end) = (a.abs(), b.abs());
+        let (lower, upper) = (start.min(end), start.max(end));
+        let orders = (self.log(upper).ceil() - self.log(lower).floor()) as usize;
+        let step = orders.div_ceil(self.tick_count.max(1)).max(1);
 
-        let domain_distance = domain_max - domain_min;
-        let range_distance = range_max - range_min;
+        let mut ticks = if self.sub_ticks && step == 1 {
+            let multiples = [1_f64, 2_f64, 5_f64]
+                .iter()
+                .copied()
+                .filter(|multiple| *multiple < self.base as f64)
+                .collect::<Vec<f64>>();
+            self.powers_between(lower, upper, &multiples, 1)
+        } else {
+            self.powers_between(lower, upper, &[1_f64], step as i32)
+        };
 
-        let tick_distance = self.compute_tick_distance();
+        // A domain within a single order of magnitude falls back to the multiples of the powers,
+        // stepped so that a large base still gives at most 10 of them.
+        if ticks.len() < 2 {
+            let base = self.base as f64;
+            let step = (base / 10_f64).ceil().max(1_f64);
+            let mut multiples = vec![1_f64];
+            multiples.extend(
+                (1..10)
+                    .map(|multiple| multiple as f64 * step)
+                    .filter(|multiple| *multiple > 1_f64 && *multiple < base),
+            );
+            ticks = self.powers_between(lower, upper, &multiples, 1);
+        }
+        if ticks.len() < 2 {
+            ticks = vec![lower, upper];
+        }
 
-        let mut ticks = vec![];
-        let mut tick = domain_min;
-        while tick <= domain_max {
-            ticks.push(tick);
-            tick += tick_distance;
+        if a < 0_f64 {
+            ticks = ticks.into_iter().rev().map(|tick| -tick).collect();
         }
+        ticks.into_iter().map(T::from_f64).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_orders_of_magnitude_evenly() {
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![1_f32, 1_000_000_f32])
+            .set_range(vec![0, 600]);
+
+        assert!((scale.scale(&1_000_f32).unwrap() - 300_f32).abs() < 1e-3);
+        assert!((scale.scale(&10_f32).unwrap() - 100_f32).abs() < 1e-3);
+        assert!((scale.invert(300_f32).unwrap() - 1_000_f32).abs() < 1e-2);
+        assert_eq!(
+            scale.get_ticks(),
+            vec![
+                1_f32,
+                10_f32,
+                100_f32,
+                1_000_f32,
+                10_000_f32,
+                100_000_f32,
+                1_000_000_f32
+            ]
+        );
+    }
+
+    #[test]
+    fn sub_ticks_and_base() {
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![1_f32, 100_f32])
+            .set_sub_ticks(true);
+        assert_eq!(
+            scale.get_ticks(),
+            vec![1_f32, 2_f32, 5_f32, 10_f32, 20_f32, 50_f32, 100_f32]
+        );
+
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![1_f32, 16_f32])
+            .set_base(2_f32);
+        assert_eq!(scale.get_ticks(), vec![1_f32, 2_f32, 4_f32, 8_f32, 16_f32]);
+
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![1_f32, 16_f32])
+            .set_base(0.5_f32);
+        assert!(matches!(scale.scale(&2_f32), Err(ChartError::Scale(_))));
+
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![2_f32, 5_f32])
+            .set_base(1e9_f32);
+        assert_eq!(scale.get_ticks(), vec![2_f32, 5_f32]);
+
+        let scale = ScaleLogarithmic::new().set_domain(vec![2_f32, 5_f32]);
+        assert_eq!(scale.get_ticks(), vec![2_f32, 3_f32, 4_f32, 5_f32]);
+    }
+
+    #[test]
+    fn rejects_domain_touching_zero() {
+        let scale = ScaleLogarithmic::new().set_domain(vec![0_f32, 100_f32]);
+        assert!(matches!(scale.scale(&10_f32), Err(ChartError::Scale(_))));
+        assert!(scale.get_ticks().is_empty());
+
+        let scale = ScaleLogarithmic::new().set_domain(vec![-100_f32, -1_f32]);
+        assert!(scale.scale(&-10_f32).is_ok());
+        assert!(matches!(scale.scale(&10_f32), Err(ChartError::Scale(_))));
+    }
+
+    #[test]
+    fn nice_and_clamp() {
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![3_f32, 420_f32])
+            .set_range(vec![0, 300])
+            .nice()
+            .clamp(true);
+        assert_eq!(scale.domain(), &vec![1_f32, 1_000_f32]);
+        assert_eq!(scale.scale(&0_f32).unwrap(), 0_f32);
+        assert_eq!(scale.scale(&5_000_f32).unwrap(), 300_f32);
 
-        ticks
+        let scale = ScaleLogarithmic::new()
+            .set_domain(vec![-0.5_f32, -20_f32])
+            .nice();
+        assert_eq!(scale.domain(), &vec![-0.1_f32, -100_f32]);
     }
 }
@@ -1,13 +1,31 @@
+use crate::error::ChartError;
+use std::fmt::Display;
+
 pub mod band;
+pub mod color;
 pub mod linear;
 pub mod logarithmic;
+pub mod nested_band;
+pub mod ordinal;
+pub mod point;
+pub mod pow;
+pub mod quantile;
+pub mod quantize;
+pub mod symlog;
+pub mod threshold;
+#[cfg(feature = "chrono")]
+pub mod time;
 
 #[derive(PartialEq)]
 pub enum ScaleType {
     Band,
+    Point,
     Ordinal,
     Linear,
     Logarithmic,
+    Symlog,
+    Pow,
+    Time,
 }
 
 /// The Scale trait defines common operations on all scales.
@@ -21,11 +39,26 @@ pub trait Scale<T> {
     fn domain_max(&self) -> f32;
 
     /// Get the range value for the given domain entry.
-    fn scale(&self, domain: &T) -> f32;
+    fn scale(&self, domain: &T) -> Result<f32, ChartError>;
+
+    /// Get the domain entry for the given range value, the reverse of `scale`.
+    /// Continuous scales map the value back numerically, while categorical scales
+    /// return the category closest to it.
+    fn invert(&self, _range: f32) -> Result<T, ChartError> {
+        Err(ChartError::Scale(String::from(
+            "The scale cannot map range values back to its domain.",
+        )))
+    }
 
     /// Get the bandwidth (if present).
     fn bandwidth(&self) -> Option<f32>;
 
+    /// Get the range values at which the scale leaves part of its domain out, where axes
+    /// draw a break marker.
+    fn range_breaks(&self) -> Vec<f32> {
+        Vec::new()
+    }
+
     /// Get the start range value.
     fn range_start(&self) -> f32;
 
@@ -39,4 +72,80 @@ pub trait Scale<T> {
 
     /// Get the list of ticks that represent the scale on a chart axis.
     fn get_ticks(&self) -> Vec<T>;
+
+    /// Get the label of a tick for scales that format their own tick labels.
+    /// Axes fall back to the tick's string representation when this returns `None`.
+    fn tick_label(&self, _tick: &T) -> Option<String> {
+        None
+    }
+
+    /// Get the groups of ticks of scales with nested categories, as the label of each group
+    /// and the start and end range values of its bands. Axes draw them as a second tier of
+    /// labels beneath the ticks.
+    fn tick_groups(&self) -> Vec<(String, f32, f32)> {
+        Vec::new()
+    }
+}
+
+/// The numeric types continuous scales can represent.
+/// Scales compute in `f64` internally, so `f64` domains keep their precision, e.g. for
+/// timestamps in nanoseconds, while `f32` domains are mapped as before.
+pub trait ScaleNumber: Copy + PartialOrd + Display + private::Sealed {
+    /// Convert the value to an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// Convert an `f64` to the closest value of the type.
+    fn from_f64(value: f64) -> Self;
+}
+
+impl ScaleNumber for f32 {
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl ScaleNumber for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+}
+
+mod private {
+    /// Prevent `ScaleNumber` from being implemented outside of the crate.
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// Return the explicit tick values that lie within the domain limits, in the order they were given.
+pub(crate) fn ticks_within_domain<T: ScaleNumber>(values: &[T], domain: &[T]) -> Vec<T> {
+    let (start, end) = match domain {
+        [start, end] if start <= end => (*start, *end),
+        [start, end] => (*end, *start),
+        _ => return Vec::new(),
+    };
+    values
+        .iter()
+        .copied()
+        .filter(|value| *value >= start && *value <= end)
+        .collect()
+}
+
+/// Return the multiple of the base raised to the given exponent.
+/// Dividing by the inverse power keeps negative exponents exact, e.g. 0.005 for 5 * 10^-3.
+pub(crate) fn scaled_power(multiple: f64, base: f64, exponent: f64) -> f64 {
+    if exponent < 0_f64 {
+        multiple / base.powf(-exponent)
+    } else {
+        multiple * base.powf(exponent)
+    }
 }
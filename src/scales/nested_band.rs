@@ -0,0 +1,388 @@
+use crate::error::ChartError;
+use crate::scales::band::nearest_band;
+use crate::scales::{Scale, ScaleType};
+use std::collections::HashMap;
+
+/// The scale to represent categorical data grouped in two levels, e.g. quarters within years.
+/// Each inner category gets its own band, the bands of an outer category are kept next to
+/// each other, and axes draw the outer categories as a second tier of tick labels.
+///
+/// Data is matched to a band through the key of its outer and inner categories, joined by
+/// the separator of the scale (see [`ScaleNestedBand::key`]).
+#[derive(Debug)]
+pub struct ScaleNestedBand {
+    /// The outer categories with the inner categories they contain.
+    groups: Vec<(String, Vec<String>)>,
+    /// The keys of the inner categories, in the order of the bands.
+    domain: Vec<String>,
+    /// The range limits of the drawable area on the chart.
+    range: Vec<isize>,
+    /// The offsets of each entry from domain.
+    offsets: Vec<f32>,
+    /// The hash map that maps domain keys with corresponding offset entries.
+    index: HashMap<String, usize>,
+    /// The inner category label of each entry from domain.
+    labels: Vec<String>,
+    /// The index of the outer category of each entry from domain.
+    group_indices: Vec<usize>,
+    /// The string placed between the outer and inner categories of a key.
+    separator: String,
+    /// The error of two pairs of categories that join to the same key, if any.
+    collision: Option<String>,
+    /// The distance between the start of the first bar and the start of the next one.
+    step: f32,
+    /// The width of a bar.
+    bandwidth: f32,
+    /// The distance between bars as a percentage of the step (between 0 and 1).
+    padding_inner: f32,
+    /// The distance from the beginning/end of the chart to the first/last bar (between 0 and 1).
+    padding_outer: f32,
+    /// The additional distance between two outer categories as a multiple of the step.
+    padding_group: f32,
+}
+
+impl Default for ScaleNestedBand {
+    fn default() -> Self {
+        Self {
+            groups: Vec::new(),
+            domain: Vec::new(),
+            range: vec![0, 1],
+            offsets: Vec::new(),
+            index: HashMap::new(),
+            labels: Vec::new(),
+            group_indices: Vec::new(),
+            separator: String::from("/"),
+            collision: None,
+            step: 1f32,
+            bandwidth: 1f32,
+            padding_inner: 0.1,
+            padding_outer: 0.1,
+            padding_group: 0.5,
+        }
+    }
+}
+
+impl ScaleNestedBand {
+    /// Create a new nested band scale with default values.
+    pub fn new() -> Self {
+        ScaleNestedBand::default()
+    }
+
+    /// Set the inner padding ratio.
+    pub fn set_inner_padding(mut self, padding: f32) -> Self {
+        self.padding_inner = padding;
+        self.rescale();
+        self
+    }
+
+    /// Set the outer padding ratio.
+    pub fn set_outer_padding(mut self, padding: f32) -> Self {
+        self.padding_outer = padding;
+        self.rescale();
+        self
+    }
+
+    /// Set the additional space between two outer categories as a multiple of the step, 0.5 by default.
+    pub fn set_group_padding(mut self, padding: f32) -> Self {
+        self.padding_group = padding.max(0f32);
+        self.rescale();
+        self
+    }
+
+    /// Set the string that joins the outer and inner categories of a key, "/" by default.
+    /// Keys that two pairs of categories share are rejected when values are scaled.
+    pub fn set_separator(mut self, separator: &str) -> Self {
+        self.separator = String::from(separator);
+        self.rescale();
+        self
+    }
+
+    /// Set the outer categories together with the inner categories each of them contains.
+    /// Duplicated outer categories are merged, and duplicated inner categories within an
+    /// outer category are kept once in their first position. Outer categories without inner
+    /// categories are left out.
+    pub fn set_domain(mut self, groups: Vec<(String, Vec<String>)>) -> Self {
+        self.groups.clear();
+
+        for (outer, inner) in groups.into_iter() {
+            let position = match self.groups.iter().position(|(name, _)| *name == outer) {
+                Some(position) => position,
+                None => {
+                    self.groups.push((outer, Vec::new()));
+                    self.groups.len() - 1
+                }
+            };
+            let entries = &mut self.groups[position].1;
+            for el in inner.into_iter() {
+                if !entries.contains(&el) {
+                    entries.push(el);
+                }
+            }
+        }
+        self.groups.retain(|(_, inner)| !inner.is_empty());
+
+        self.rescale();
+        self
+    }
+
+    /// Get the outer categories with the inner categories they contain.
+    pub fn groups(&self) -> &Vec<(String, Vec<String>)> {
+        &self.groups
+    }
+
+    /// Get the keys of the inner categories, in the order of the bands.
+    pub fn domain(&self) -> &Vec<String> {
+        &self.domain
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.range = range;
+        self.rescale();
+        self
+    }
+
+    /// Get the range limits of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        &self.range
+    }
+
+    /// Get the key the data of an inner category within an outer category is matched with.
+    pub fn key(&self, outer: &str, inner: &str) -> String {
+        format!("{}{}{}", outer, self.separator, inner)
+    }
+
+    /// Check that every pair of outer and inner categories has its own key.
+    fn check_domain(&self) -> Result<(), ChartError> {
+        match &self.collision {
+            Some(message) => Err(ChartError::Scale(message.clone())),
+            None => Ok(()),
+        }
+    }
+
+    fn rescale(&mut self) {
+        self.domain.clear();
+        self.labels.clear();
+        self.index.clear();
+        self.group_indices.clear();
+        self.collision = None;
+        for (group_index, (outer, inner)) in self.groups.iter().enumerate() {
+            for el in inner.iter() {
+                let key = self.key(outer, el);
+                // Categories that contain the separator can join to the key of another pair,
+                // e.g. ("a/b", "c") and ("a", "b/c"), which would leave one of them without a band.
+                if self.index.contains_key(&key) {
+                    if self.collision.is_none() {
+                        self.collision = Some(format!(
+                            "The categories \"{}\" and \"{}\" join to the key \"{}\" of another band, use a separator that is not part of the category names.",
+                            outer, el, key
+                        ));
+                    }
+                } else {
+                    self.index.insert(key.clone(), self.domain.len());
+                    self.domain.push(key);
+                    self.labels.push(el.clone());
+                    self.group_indices.push(group_index);
+                }
+            }
+        }
+
+        let n = self.domain.len();
+        let gaps = self.groups.len().saturating_sub(1) as f32 * self.padding_group;
+        let r0 = self.range[0] as f32;
+        let r1 = self.range[1] as f32;
+        let reverse = r1 < r0;
+        let (lower, upper) = if reverse { (r1, r0) } else { (r0, r1) };
+
+        let step_denominator =
+            (n as f32 - self.padding_inner + self.padding_outer * 2f32 + gaps).max(1f32);
+        self.step = (upper - lower) / step_denominator;
+        self.bandwidth = self.step * (1f32 - self.padding_inner);
+
+        let start =
+            lower + (upper - lower - self.step * (n as f32 - self.padding_inner + gaps)) / 2f32;
+        self.offsets = self
+            .group_indices
+            .iter()
+            .enumerate()
+            .map(|(i, group_index)| {
+                start + self.step * (i as f32 + *group_index as f32 * self.padding_group)
+            })
+            .collect();
+
+        // Mirror the bands so that the first category is at the start of a reversed range.
+        if reverse {
+            for offset in self.offsets.iter_mut() {
+                *offset = lower + upper - *offset - self.bandwidth;
+            }
+        }
+    }
+}
+
+impl Scale<String> for ScaleNestedBand {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Band
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<String> {
+        self.domain().clone()
+    }
+
+    fn domain_max(&self) -> f32 {
+        0_f32
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, domain: &String) -> Result<f32, ChartError> {
+        self.check_domain()?;
+        match self.index.get(domain) {
+            Some(index) => Ok(self.offsets[*index]),
+            None => Err(ChartError::Scale(format!(
+                "The category \"{}\" is not part of the nested band scale domain.",
+                domain
+            ))),
+        }
+    }
+
+    /// Get the key of the band whose center is the closest to the given range value.
+    fn invert(&self, range: f32) -> Result<String, ChartError> {
+        self.check_domain()?;
+        nearest_band(&self.domain, &self.offsets, self.bandwidth, range)
+            .cloned()
+            .ok_or_else(|| {
+                ChartError::Scale(String::from("The nested band scale domain is empty."))
+            })
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        Some(self.bandwidth)
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.range[0] as f32
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.range[1] as f32
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    fn get_ticks(&self) -> Vec<String> {
+        if self.check_domain().is_err() {
+            return Vec::new();
+        }
+        self.domain.clone()
+    }
+
+    /// Label the ticks with the inner category only, the outer one is drawn as a group.
+    fn tick_label(&self, tick: &String) -> Option<String> {
+        self.index
+            .get(tick)
+            .map(|index| self.labels[*index].clone())
+    }
+
+    /// Get the outer categories with the extent of their bands.
+    fn tick_groups(&self) -> Vec<(String, f32, f32)> {
+        let mut groups = Vec::new();
+        for (group_index, (outer, _)) in self.groups.iter().enumerate() {
+            let extent = self
+                .group_indices
+                .iter()
+                .zip(self.offsets.iter())
+                .filter(|(index, _)| **index == group_index)
+                .fold(
+                    None,
+                    |extent: Option<(f32, f32)>, (_, offset)| match extent {
+                        Some((start, end)) => Some((start.min(*offset), end.max(*offset))),
+                        None => Some((*offset, *offset)),
+                    },
+                );
+            if let Some((start, end)) = extent {
+                groups.push((outer.clone(), start, end + self.bandwidth));
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quarters() -> Vec<(String, Vec<String>)> {
+        vec![
+            (
+                String::from("2023"),
+                vec![String::from("Q1"), String::from("Q2")],
+            ),
+            (
+                String::from("2024"),
+                vec![String::from("Q1"), String::from("Q2")],
+            ),
+        ]
+    }
+
+    #[test]
+    fn groups_keep_their_bands_together() {
+        let scale = ScaleNestedBand::new()
+            .set_domain(quarters())
+            .set_range(vec![0, 500])
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32)
+            .set_group_padding(1_f32);
+
+        assert_eq!(scale.bandwidth(), Some(100_f32));
+        assert_eq!(scale.scale(&scale.key("2023", "Q2")).unwrap(), 100_f32);
+        assert_eq!(scale.scale(&scale.key("2024", "Q1")).unwrap(), 300_f32);
+        assert_eq!(
+            scale.tick_label(&String::from("2024/Q1")),
+            Some(String::from("Q1"))
+        );
+        assert_eq!(
+            scale.tick_groups(),
+            vec![
+                (String::from("2023"), 0_f32, 200_f32),
+                (String::from("2024"), 300_f32, 500_f32)
+            ]
+        );
+    }
+
+    #[test]
+    fn reversed_range_mirrors_the_bands() {
+        let scale = ScaleNestedBand::new()
+            .set_domain(quarters())
+            .set_range(vec![500, 0])
+            .set_inner_padding(0_f32)
+            .set_outer_padding(0_f32)
+            .set_group_padding(1_f32);
+
+        assert_eq!(scale.scale(&scale.key("2023", "Q1")).unwrap(), 400_f32);
+        assert_eq!(scale.scale(&scale.key("2024", "Q2")).unwrap(), 0_f32);
+        assert_eq!(scale.invert(250_f32).unwrap(), "2023/Q2");
+    }
+
+    #[test]
+    fn rejects_categories_joining_to_the_same_key() {
+        let groups = vec![
+            (String::from("a/b"), vec![String::from("c")]),
+            (String::from("a"), vec![String::from("b/c")]),
+        ];
+        let scale = ScaleNestedBand::new().set_domain(groups.clone());
+        assert!(matches!(
+            scale.scale(&String::from("a/b/c")),
+            Err(ChartError::Scale(_))
+        ));
+        assert!(scale.get_ticks().is_empty());
+
+        let scale = ScaleNestedBand::new()
+            .set_domain(groups)
+            .set_separator(" | ");
+        assert_eq!(scale.get_ticks().len(), 2);
+        assert!(scale.scale(&scale.key("a", "b/c")).is_ok());
+    }
+}
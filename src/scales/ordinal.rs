@@ -0,0 +1,150 @@
+use crate::error::ChartError;
+use crate::scales::{Scale, ScaleType};
+use std::collections::HashMap;
+
+/// The scale to represent categorical data at explicit positions.
+/// Each domain entry is mapped to the range position with the same index, and the range
+/// positions are reused from the start when the domain has more entries than the range.
+#[derive(Debug, Default)]
+pub struct ScaleOrdinal {
+    /// The distinct categories of the dataset that the scale is going to represent.
+    domain: Vec<String>,
+    /// The positions on the chart the categories are mapped to.
+    range: Vec<isize>,
+    /// The hash map that maps domain keys with their index in the domain.
+    index: HashMap<String, usize>,
+}
+
+impl ScaleOrdinal {
+    /// Create a new ordinal scale with default values.
+    pub fn new() -> Self {
+        ScaleOrdinal::default()
+    }
+
+    /// Set the categories of the scale, duplicated entries are kept once in their first position.
+    pub fn set_domain(mut self, range: Vec<String>) -> Self {
+        self.domain.clear();
+        self.index.clear();
+
+        for el in range.into_iter() {
+            if !self.index.contains_key(&el) {
+                self.index.insert(el.clone(), self.domain.len());
+                self.domain.push(el);
+            }
+        }
+
+        self
+    }
+
+    /// Get the domain of the scale.
+    pub fn domain(&self) -> &Vec<String> {
+        &self.domain
+    }
+
+    /// Set the positions the categories are mapped to, in the order of the domain.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the range positions of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        &self.range
+    }
+}
+
+impl Scale<String> for ScaleOrdinal {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Ordinal
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<String> {
+        self.domain().clone()
+    }
+
+    fn domain_max(&self) -> f32 {
+        0_f32
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, domain: &String) -> Result<f32, ChartError> {
+        let index = self.index.get(domain).ok_or_else(|| {
+            ChartError::Scale(format!(
+                "The category \"{}\" is not part of the ordinal scale domain.",
+                domain
+            ))
+        })?;
+
+        if self.range.is_empty() {
+            return Err(ChartError::Scale(String::from(
+                "The ordinal scale has no range positions to map categories to.",
+            )));
+        }
+
+        Ok(self.range[index % self.range.len()] as f32)
+    }
+
+    /// Get the category whose position is the closest to the given range value.
+    fn invert(&self, range: f32) -> Result<String, ChartError> {
+        if self.range.is_empty() {
+            return Err(ChartError::Scale(String::from(
+                "The ordinal scale has no range positions to map categories to.",
+            )));
+        }
+
+        self.domain
+            .iter()
+            .enumerate()
+            .map(|(index, category)| (category, self.range[index % self.range.len()] as f32))
+            .min_by(|(_, a), (_, b)| (a - range).abs().total_cmp(&(b - range).abs()))
+            .map(|(category, _)| category.clone())
+            .ok_or_else(|| ChartError::Scale(String::from("The ordinal scale domain is empty.")))
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        Some(0_f32)
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.range.first().copied().unwrap_or(0) as f32
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.range.last().copied().unwrap_or(0) as f32
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    fn get_ticks(&self) -> Vec<String> {
+        self.domain.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range_positions_cycle() {
+        let scale = ScaleOrdinal::new()
+            .set_domain(vec![
+                String::from("A"),
+                String::from("B"),
+                String::from("C"),
+                String::from("A"),
+            ])
+            .set_range(vec![0, 30]);
+
+        assert_eq!(scale.domain().len(), 3);
+        assert_eq!(scale.scale(&String::from("B")).unwrap(), 30_f32);
+        assert_eq!(scale.scale(&String::from("C")).unwrap(), 0_f32);
+        assert!(matches!(
+            scale.scale(&String::from("D")),
+            Err(ChartError::Scale(_))
+        ));
+    }
+}
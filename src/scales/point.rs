@@ -0,0 +1,216 @@
+use crate::error::ChartError;
+use crate::scales::{Scale, ScaleType};
+use std::collections::HashMap;
+
+/// The scale to represent categorical data as points.
+/// It is a band scale with no bandwidth, which suits line and scatter charts over categories.
+#[derive(Debug)]
+pub struct ScalePoint {
+    /// The distinct categories of the dataset that the scale is going to represent.
+    domain: Vec<String>,
+    /// The range limits of the drawable area on the chart.
+    range: Vec<isize>,
+    /// The offsets of each entry from domain.
+    offsets: Vec<f32>,
+    /// The hash map that maps domain keys with corresponding offset entries.
+    index: HashMap<String, usize>,
+    /// The distance between two adjacent points.
+    step: f32,
+    /// The distance from the beginning/end of the chart to the first/last point as a multiple of the step.
+    padding: f32,
+    /// The distribution of the outer padding between the first and last points (between 0 and 1).
+    /// An align value of 0.5 will distribute space evenly, while 0 will move all outer space to
+    /// the right part, leaving no space on the left.
+    align: f32,
+}
+
+impl Default for ScalePoint {
+    fn default() -> Self {
+        Self {
+            domain: Vec::new(),
+            range: vec![0, 1],
+            offsets: Vec::new(),
+            index: HashMap::new(),
+            step: 1f32,
+            padding: 0f32,
+            align: 0.5,
+        }
+    }
+}
+
+impl ScalePoint {
+    /// Create a new point scale with default values.
+    pub fn new() -> Self {
+        ScalePoint::default()
+    }
+
+    /// Set the outer padding as a multiple of the distance between two points.
+    /// With the default padding of 0 the first and last points lie on the range limits.
+    pub fn set_padding(mut self, padding: f32) -> Self {
+        self.padding = padding.max(0f32);
+        self.rescale();
+        self
+    }
+
+    /// Set how the outer padding is distributed before the first and after the last point.
+    /// The value is clamped between 0 (all the padding after) and 1 (all the padding before).
+    pub fn set_align(mut self, align: f32) -> Self {
+        self.align = align.clamp(0f32, 1f32);
+        self.rescale();
+        self
+    }
+
+    /// Set the domain limits for the scale, duplicated entries are kept once in their first position.
+    pub fn set_domain(mut self, range: Vec<String>) -> Self {
+        self.domain.clear();
+        self.index.clear();
+
+        for el in range.into_iter() {
+            if !self.index.contains_key(&el) {
+                self.index.insert(el.clone(), self.domain.len());
+                self.domain.push(el);
+            }
+        }
+
+        self.rescale();
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<String> {
+        &self.domain
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.range = range;
+        self.rescale();
+        self
+    }
+
+    /// Get the range limits of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        &self.range
+    }
+
+    /// Get the distance between two adjacent points.
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    fn rescale(&mut self) {
+        let n = self.domain.len();
+        let r0 = self.range[0] as f32;
+        let r1 = self.range[1] as f32;
+        let reverse = r1 < r0;
+        let (mut start, stop) = if reverse { (r1, r0) } else { (r0, r1) };
+
+        let step_denominator = (n as f32 - 1f32 + self.padding * 2f32).max(1f32);
+        self.step = (stop - start) / step_denominator;
+        start += (stop - start - self.step * (n as f32 - 1f32).max(0f32)) * self.align;
+
+        self.offsets.clear();
+        for i in 0..n {
+            self.offsets.push(start + self.step * i as f32);
+        }
+
+        if reverse {
+            self.offsets.reverse();
+        }
+    }
+}
+
+impl Scale<String> for ScalePoint {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Point
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<String> {
+        self.domain().clone()
+    }
+
+    fn domain_max(&self) -> f32 {
+        0_f32
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, domain: &String) -> Result<f32, ChartError> {
+        match self.index.get(domain) {
+            Some(index) => Ok(self.offsets[*index]),
+            None => Err(ChartError::Scale(format!(
+                "The category \"{}\" is not part of the point scale domain.",
+                domain
+            ))),
+        }
+    }
+
+    /// Get the category whose point is the closest to the given range value.
+    fn invert(&self, range: f32) -> Result<String, ChartError> {
+        self.domain
+            .iter()
+            .zip(self.offsets.iter())
+            .min_by(|(_, a), (_, b)| (*a - range).abs().total_cmp(&(*b - range).abs()))
+            .map(|(category, _)| category.clone())
+            .ok_or_else(|| ChartError::Scale(String::from("The point scale domain is empty.")))
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        Some(0_f32)
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.range[0] as f32
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.range[1] as f32
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    fn get_ticks(&self) -> Vec<String> {
+        self.domain.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn months() -> Vec<String> {
+        vec![
+            String::from("Jan"),
+            String::from("Feb"),
+            String::from("Mar"),
+        ]
+    }
+
+    #[test]
+    fn points_span_the_range() {
+        let scale = ScalePoint::new()
+            .set_domain(months())
+            .set_range(vec![0, 100]);
+
+        assert_eq!(scale.scale(&String::from("Jan")).unwrap(), 0_f32);
+        assert_eq!(scale.scale(&String::from("Feb")).unwrap(), 50_f32);
+        assert_eq!(scale.scale(&String::from("Mar")).unwrap(), 100_f32);
+        assert_eq!(scale.bandwidth(), Some(0_f32));
+    }
+
+    #[test]
+    fn padding_and_align_move_the_points() {
+        let scale = ScalePoint::new()
+            .set_domain(months())
+            .set_range(vec![0, 120])
+            .set_padding(1_f32)
+            .set_align(0_f32);
+
+        assert_eq!(scale.step(), 30_f32);
+        assert_eq!(scale.scale(&String::from("Jan")).unwrap(), 0_f32);
+        assert_eq!(scale.scale(&String::from("Mar")).unwrap(), 60_f32);
+    }
+}
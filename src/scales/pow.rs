@@ -0,0 +1,369 @@
+use crate::error::ChartError;
+use crate::scales::linear::ScaleLinear;
+use crate::scales::{ticks_within_domain, Scale, ScaleType};
+
+/// The scale to represent data through a power function.
+/// Values are mapped through `sign(x) * |x|^exponent`, so exponents below 1 stretch the
+/// small values and exponents above 1 stretch the large ones.
+#[derive(Debug)]
+pub struct ScalePow {
+    /// The domain limits of the dataset that the scale is going to represent.
+    domain: Vec<f32>,
+    /// The range limits of the drawable area on the chart.
+    range: Vec<isize>,
+    /// The exponent of the power function.
+    exponent: f32,
+    /// The amount of ticks to display.
+    tick_count: usize,
+    /// The values to display ticks at, computed from the tick count when not set.
+    tick_values: Option<Vec<f32>>,
+}
+
+impl Default for ScalePow {
+    fn default() -> Self {
+        Self {
+            domain: Vec::new(),
+            range: vec![0, 1],
+            exponent: 1.,
+            tick_count: 10,
+            tick_values: None,
+        }
+    }
+}
+
+impl ScalePow {
+    /// Create a new power scale with default values.
+    pub fn new() -> Self {
+        ScalePow::default()
+    }
+
+    /// Set the domain limits for the scale.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.domain = range;
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        &self.domain
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the range limits of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        &self.range
+    }
+
+    /// Set the exponent of the power function, 1 by default.
+    /// A negative exponent needs a domain that neither crosses nor touches zero.
+    pub fn set_exponent(mut self, exponent: f32) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Get the exponent of the power function.
+    pub fn exponent(&self) -> f32 {
+        self.exponent
+    }
+
+    /// Set the approximate amount of ticks to display, 10 by default.
+    /// The ticks are still placed on round values, so the actual amount may differ slightly.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self
+    }
+
+    /// Set the exact values to display ticks at instead of computing them.
+    /// Values outside of the domain are not displayed.
+    pub fn set_tick_values(mut self, tick_values: Vec<f32>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    /// Check that the exponent and the domain can be represented.
+    fn check_domain(&self) -> Result<(), ChartError> {
+        if !self.exponent.is_finite() || self.exponent == 0_f32 {
+            return Err(ChartError::Scale(format!(
+                "the exponent of a power scale must be finite and different from 0, got {}",
+                self.exponent
+            )));
+        }
+        if self.domain.len() != 2 {
+            return Err(ChartError::Scale(format!(
+                "a power scale domain needs a start and an end, got {} values",
+                self.domain.len()
+            )));
+        }
+
+        // A negative exponent sends 0 to infinity, so the domain must stay on one side of it.
+        let (start, end) = (self.domain[0], self.domain[1]);
+        if self.exponent < 0_f32 && start * end <= 0_f32 {
+            return Err(ChartError::Scale(format!(
+                "the power scale domain [{}, {}] crosses or touches zero, which a negative exponent of {} cannot represent",
+                start, end, self.exponent
+            )));
+        }
+        Ok(())
+    }
+
+    /// Raise the magnitude of a value to the exponent, keeping its sign.
+    fn transform(&self, x: f32) -> f32 {
+        x.signum() * x.abs().powf(self.exponent)
+    }
+
+    /// Reverse the power function of a value.
+    fn untransform(&self, y: f32) -> f32 {
+        y.signum() * y.abs().powf(1_f32 / self.exponent)
+    }
+
+    /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
+    fn normalize(&self, a: f32, b: f32, x: f32) -> f32 {
+        // If a == b then return 0.5
+        if (a - b).abs() < f32::EPSILON {
+            0.5
+        } else {
+            (x - a) / (b - a)
+        }
+    }
+
+    /// Takes a value t in [0, 1] and returns the corresponding range in [a, b].
+    fn interpolate(&self, a: f32, b: f32, t: f32) -> f32 {
+        (b - a) * t + a
+    }
+}
+
+impl Scale<f32> for ScalePow {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Pow
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<f32> {
+        self.domain().clone()
+    }
+
+    /// Get the domain max of the scale.
+    fn domain_max(&self) -> f32 {
+        self.domain.get(1).copied().unwrap_or(0_f32)
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, x: &f32) -> Result<f32, ChartError> {
+        self.check_domain()?;
+        let normalized = self.normalize(
+            self.transform(self.domain[0]),
+            self.transform(self.domain[1]),
+            self.transform(*x),
+        );
+        Ok(self.interpolate(self.range[0] as f32, self.range[1] as f32, normalized))
+    }
+
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Result<f32, ChartError> {
+        self.check_domain()?;
+        let normalized = self.normalize(self.range[0] as f32, self.range[1] as f32, range);
+        let transformed = self.interpolate(
+            self.transform(self.domain[0]),
+            self.transform(self.domain[1]),
+            normalized,
+        );
+        Ok(self.untransform(transformed))
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        Some(0_f32)
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.range[0] as f32
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.range[1] as f32
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    /// The ticks are the round values a linear scale over the same domain would use.
+    fn get_ticks(&self) -> Vec<f32> {
+        if self.check_domain().is_err() {
+            return Vec::new();
+        }
+        if let Some(values) = &self.tick_values {
+            return ticks_within_domain(values, &self.domain);
+        }
+        ScaleLinear::new()
+            .set_domain(self.domain.clone())
+            .set_tick_count(self.tick_count)
+            .get_ticks()
+    }
+}
+
+/// The scale to represent data through a square root, a power scale with an exponent of 0.5.
+/// Mapping values to the radius of markers through it makes the marker areas proportional
+/// to the values.
+#[derive(Debug)]
+pub struct ScaleSqrt {
+    /// The power scale the values are mapped with.
+    scale: ScalePow,
+}
+
+impl Default for ScaleSqrt {
+    fn default() -> Self {
+        Self {
+            scale: ScalePow::new().set_exponent(0.5),
+        }
+    }
+}
+
+impl ScaleSqrt {
+    /// Create a new square root scale with default values.
+    pub fn new() -> Self {
+        ScaleSqrt::default()
+    }
+
+    /// Set the domain limits for the scale.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.scale = self.scale.set_domain(range);
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        self.scale.domain()
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.scale = self.scale.set_range(range);
+        self
+    }
+
+    /// Get the range limits of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        self.scale.range()
+    }
+
+    /// Set the approximate amount of ticks to display, 10 by default.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.scale = self.scale.set_tick_count(tick_count);
+        self
+    }
+
+    /// Set the exact values to display ticks at instead of computing them.
+    /// Values outside of the domain are not displayed.
+    pub fn set_tick_values(mut self, tick_values: Vec<f32>) -> Self {
+        self.scale = self.scale.set_tick_values(tick_values);
+        self
+    }
+}
+
+impl Scale<f32> for ScaleSqrt {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        self.scale.get_type()
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<f32> {
+        self.scale.get_domain()
+    }
+
+    /// Get the domain max of the scale.
+    fn domain_max(&self) -> f32 {
+        self.scale.domain_max()
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, x: &f32) -> Result<f32, ChartError> {
+        self.scale.scale(x)
+    }
+
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Result<f32, ChartError> {
+        self.scale.invert(range)
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        self.scale.bandwidth()
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.scale.range_start()
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.scale.range_end()
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    fn get_ticks(&self) -> Vec<f32> {
+        self.scale.get_ticks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sqrt_maps_areas() {
+        let scale = ScaleSqrt::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec![0, 10]);
+
+        assert_eq!(scale.scale(&25_f32).unwrap(), 5_f32);
+        assert_eq!(scale.scale(&100_f32).unwrap(), 10_f32);
+        assert_eq!(
+            scale.get_ticks(),
+            vec![
+                0_f32, 10_f32, 20_f32, 30_f32, 40_f32, 50_f32, 60_f32, 70_f32, 80_f32, 90_f32,
+                100_f32
+            ]
+        );
+    }
+
+    #[test]
+    fn pow_keeps_the_sign() {
+        let scale = ScalePow::new()
+            .set_domain(vec![-10_f32, 10_f32])
+            .set_range(vec![0, 200])
+            .set_exponent(2_f32);
+
+        assert_eq!(scale.scale(&0_f32).unwrap(), 100_f32);
+        assert_eq!(scale.scale(&-5_f32).unwrap(), 75_f32);
+        assert_eq!(scale.scale(&5_f32).unwrap(), 125_f32);
+    }
+
+    #[test]
+    fn empty_domain_has_zero_max() {
+        assert_eq!(ScalePow::new().domain_max(), 0_f32);
+        assert_eq!(ScaleSqrt::new().domain_max(), 0_f32);
+    }
+
+    #[test]
+    fn negative_exponent_rejects_zero() {
+        let scale = ScalePow::new()
+            .set_domain(vec![0_f32, 10_f32])
+            .set_exponent(-1_f32);
+        assert!(matches!(scale.scale(&5_f32), Err(ChartError::Scale(_))));
+        assert!(scale.get_ticks().is_empty());
+
+        let scale = ScalePow::new()
+            .set_domain(vec![1_f32, 10_f32])
+            .set_range(vec![0, 90])
+            .set_exponent(-1_f32);
+        assert_eq!(scale.scale(&10_f32).unwrap(), 90_f32);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::error::ChartError;
+
+/// The scale to divide a continuous domain into bins holding the same amount of samples.
+/// The domain is a sample of the data, and the bin boundaries are its quantiles, so a
+/// range of 4 outputs maps each quarter of the sample to one output.
+#[derive(Debug)]
+pub struct ScaleQuantile<R> {
+    /// The sample values, sorted in ascending order.
+    domain: Vec<f32>,
+    /// The outputs the bins are mapped to.
+    range: Vec<R>,
+}
+
+impl<R> Default for ScaleQuantile<R> {
+    fn default() -> Self {
+        Self {
+            domain: Vec::new(),
+            range: Vec::new(),
+        }
+    }
+}
+
+impl<R> ScaleQuantile<R> {
+    /// Create a new quantile scale with default values.
+    pub fn new() -> Self {
+        ScaleQuantile::default()
+    }
+
+    /// Set the sample values the quantiles are computed from.
+    /// The values do not need to be sorted, and NaN values are ignored.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        let mut sample = range
+            .into_iter()
+            .filter(|value| !value.is_nan())
+            .collect::<Vec<f32>>();
+        sample.sort_by(|a, b| a.total_cmp(b));
+        self.domain = sample;
+        self
+    }
+
+    /// Get the sample values of the scale, sorted in ascending order.
+    pub fn domain(&self) -> &Vec<f32> {
+        &self.domain
+    }
+
+    /// Set the outputs of the scale, one per bin.
+    pub fn set_range(mut self, range: Vec<R>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the outputs of the scale.
+    pub fn range(&self) -> &Vec<R> {
+        &self.range
+    }
+
+    /// Return the p-quantile of the sample, interpolating between the closest samples.
+    fn quantile(&self, p: f32) -> f32 {
+        let position = (self.domain.len() - 1) as f32 * p;
+        let lower = position.floor() as usize;
+        let upper = (lower + 1).min(self.domain.len() - 1);
+        let (a, b) = (self.domain[lower], self.domain[upper]);
+
+        a + (b - a) * (position - lower as f32)
+    }
+
+    /// Get the values that separate the bins, one less than the amount of outputs.
+    pub fn thresholds(&self) -> Vec<f32> {
+        if self.domain.is_empty() {
+            return Vec::new();
+        }
+        let n = self.range.len();
+
+        (1..n).map(|i| self.quantile(i as f32 / n as f32)).collect()
+    }
+
+    /// Get the output for the given domain value.
+    pub fn scale(&self, value: f32) -> Result<&R, ChartError> {
+        if self.domain.is_empty() || self.range.is_empty() || value.is_nan() {
+            return Err(ChartError::Scale(format!(
+                "{} cannot be mapped by a quantile scale with {} samples and {} outputs",
+                value,
+                self.domain.len(),
+                self.range.len()
+            )));
+        }
+
+        let index = self
+            .thresholds()
+            .partition_point(|threshold| *threshold <= value);
+        Ok(&self.range[index])
+    }
+
+    /// Get the domain limits of the bin that is mapped to the output with the given index.
+    /// The first and last bins start and end at the smallest and largest samples.
+    pub fn invert_extent(&self, index: usize) -> Option<(f32, f32)> {
+        if index >= self.range.len() || self.domain.is_empty() {
+            return None;
+        }
+        let thresholds = self.thresholds();
+        let start = if index == 0 {
+            self.domain[0]
+        } else {
+            thresholds[index - 1]
+        };
+        let end = thresholds
+            .get(index)
+            .copied()
+            .unwrap_or(self.domain[self.domain.len() - 1]);
+
+        Some((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bins_hold_the_same_amount_of_samples() {
+        let scale = ScaleQuantile::new()
+            .set_domain(vec![9_f32, 1_f32, 3_f32, 7_f32, 5_f32])
+            .set_range(vec!["bottom", "top"]);
+
+        assert_eq!(scale.domain(), &vec![1_f32, 3_f32, 5_f32, 7_f32, 9_f32]);
+        assert_eq!(scale.thresholds(), vec![5_f32]);
+        assert_eq!(*scale.scale(4_f32).unwrap(), "bottom");
+        assert_eq!(*scale.scale(5_f32).unwrap(), "top");
+        assert_eq!(scale.invert_extent(1), Some((5_f32, 9_f32)));
+    }
+}
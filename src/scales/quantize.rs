@@ -0,0 +1,121 @@
+use crate::error::ChartError;
+
+/// The scale to divide a continuous domain into bins of equal width.
+/// Each bin is mapped to one of the range outputs, e.g. colors or categories,
+/// so a range of 4 outputs splits the domain into 4 bins of the same width.
+/// Values outside of the domain fall into the first or the last bin.
+#[derive(Debug)]
+pub struct ScaleQuantize<R> {
+    /// The domain limits of the dataset that the scale is going to represent.
+    domain: Vec<f32>,
+    /// The outputs the bins are mapped to.
+    range: Vec<R>,
+}
+
+impl<R> Default for ScaleQuantize<R> {
+    fn default() -> Self {
+        Self {
+            domain: vec![0., 1.],
+            range: Vec::new(),
+        }
+    }
+}
+
+impl<R> ScaleQuantize<R> {
+    /// Create a new quantize scale with default values.
+    pub fn new() -> Self {
+        ScaleQuantize::default()
+    }
+
+    /// Set the domain limits for the scale.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.domain = range;
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        &self.domain
+    }
+
+    /// Set the outputs of the scale, one per bin.
+    pub fn set_range(mut self, range: Vec<R>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the outputs of the scale.
+    pub fn range(&self) -> &Vec<R> {
+        &self.range
+    }
+
+    /// Get the values that separate the bins, one less than the amount of outputs.
+    pub fn thresholds(&self) -> Vec<f32> {
+        let (start, end) = match self.domain.as_slice() {
+            [start, end] => (*start, *end),
+            _ => return Vec::new(),
+        };
+        let n = self.range.len();
+
+        (1..n)
+            .map(|i| start + (end - start) * i as f32 / n as f32)
+            .collect()
+    }
+
+    /// Get the output for the given domain value.
+    pub fn scale(&self, value: f32) -> Result<&R, ChartError> {
+        if self.domain.len() != 2 {
+            return Err(ChartError::Scale(format!(
+                "a quantize scale domain needs a start and an end, got {} values",
+                self.domain.len()
+            )));
+        }
+        if self.range.is_empty() || value.is_nan() {
+            return Err(ChartError::Scale(format!(
+                "{} cannot be mapped by a quantize scale with {} outputs",
+                value,
+                self.range.len()
+            )));
+        }
+
+        let index = self
+            .thresholds()
+            .partition_point(|threshold| *threshold <= value);
+        Ok(&self.range[index])
+    }
+
+    /// Get the domain limits of the bin that is mapped to the output with the given index.
+    pub fn invert_extent(&self, index: usize) -> Option<(f32, f32)> {
+        if index >= self.range.len() || self.domain.len() != 2 {
+            return None;
+        }
+        let thresholds = self.thresholds();
+        let start = if index == 0 {
+            self.domain[0]
+        } else {
+            thresholds[index - 1]
+        };
+        let end = thresholds.get(index).copied().unwrap_or(self.domain[1]);
+
+        Some((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bins_have_equal_width() {
+        let scale = ScaleQuantize::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec!["low", "medium", "high", "extreme"]);
+
+        assert_eq!(scale.thresholds(), vec![25_f32, 50_f32, 75_f32]);
+        assert_eq!(*scale.scale(-10_f32).unwrap(), "low");
+        assert_eq!(*scale.scale(50_f32).unwrap(), "high");
+        assert_eq!(*scale.scale(500_f32).unwrap(), "extreme");
+        assert_eq!(scale.invert_extent(1), Some((25_f32, 50_f32)));
+        assert!(ScaleQuantize::<&str>::new().scale(1_f32).is_err());
+    }
+}
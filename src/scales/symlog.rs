@@ -0,0 +1,295 @@
+use crate::error::ChartError;
+use crate::scales::{scaled_power, ticks_within_domain, Scale, ScaleType};
+
+/// The scale to represent data that spans both negative and positive orders of magnitude.
+/// Values are mapped through `sign(x) * ln(1 + |x| / constant)`, which is linear around
+/// zero and logarithmic away from it, so unlike a logarithmic scale the domain may cross zero.
+#[derive(Debug)]
+pub struct ScaleSymlog {
+    /// The domain limits of the dataset that the scale is going to represent.
+    domain: Vec<f32>,
+    /// The range limits of the drawable area on the chart.
+    range: Vec<isize>,
+    /// The magnitude below which the scale is approximately linear.
+    constant: f32,
+    /// The amount of ticks to display.
+    tick_count: usize,
+    /// The values to display ticks at, computed from the tick count when not set.
+    tick_values: Option<Vec<f32>>,
+}
+
+impl Default for ScaleSymlog {
+    fn default() -> Self {
+        Self {
+            domain: Vec::new(),
+            range: vec![0, 1],
+            constant: 1.,
+            tick_count: 10,
+            tick_values: None,
+        }
+    }
+}
+
+impl ScaleSymlog {
+    /// Create a new symmetric logarithmic scale with default values.
+    pub fn new() -> Self {
+        ScaleSymlog::default()
+    }
+
+    /// Set the domain limits for the scale.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.domain = range;
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        &self.domain
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the range limits of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        &self.range
+    }
+
+    /// Set the magnitude below which the scale is approximately linear, 1 by default.
+    /// Raising it gives more room to the values around zero.
+    pub fn set_constant(mut self, constant: f32) -> Self {
+        self.constant = constant;
+        self
+    }
+
+    /// Get the magnitude below which the scale is approximately linear.
+    pub fn constant(&self) -> f32 {
+        self.constant
+    }
+
+    /// Set the approximate amount of ticks to display, 10 by default.
+    /// The ticks are still placed on powers of 10, so the actual amount may differ slightly.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self
+    }
+
+    /// Set the exact values to display ticks at instead of computing them.
+    /// Values outside of the domain are not displayed.
+    pub fn set_tick_values(mut self, tick_values: Vec<f32>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    /// Check that the constant and the domain can be represented.
+    fn check_domain(&self) -> Result<(), ChartError> {
+        if !self.constant.is_finite() || self.constant <= 0_f32 {
+            return Err(ChartError::Scale(format!(
+                "the constant of a symlog scale must be positive, got {}",
+                self.constant
+            )));
+        }
+        if self.domain.len() != 2 {
+            return Err(ChartError::Scale(format!(
+                "a symlog scale domain needs a start and an end, got {} values",
+                self.domain.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Apply the symmetric logarithm to a value.
+    fn transform(&self, x: f32) -> f32 {
+        x.signum() * (x.abs() / self.constant).ln_1p()
+    }
+
+    /// Reverse the symmetric logarithm of a value.
+    fn untransform(&self, y: f32) -> f32 {
+        y.signum() * y.abs().exp_m1() * self.constant
+    }
+
+    /// Takes a value x in [a, b] and returns the corresponding value in [0, 1].
+    fn normalize(&self, a: f32, b: f32, x: f32) -> f32 {
+        // If a == b then return 0.5
+        if (a - b).abs() < f32::EPSILON {
+            0.5
+        } else {
+            (x - a) / (b - a)
+        }
+    }
+
+    /// Takes a value t in [0, 1] and returns the corresponding range in [a, b].
+    fn interpolate(&self, a: f32, b: f32, t: f32) -> f32 {
+        (b - a) * t + a
+    }
+
+    /// Return the exponents of the powers of 10 between the given magnitudes,
+    /// skipping those within the linear part of the scale.
+    fn exponents_between(&self, lower: f32, upper: f32) -> Vec<i32> {
+        if upper <= 0_f32 {
+            return Vec::new();
+        }
+        // Allow for the rounding errors of the powers at the domain limits.
+        let first = (lower.max(self.constant).log10() - 1e-6).ceil() as i32;
+        let last = (upper.log10() + 1e-6).floor() as i32;
+        (first..=last).collect()
+    }
+}
+
+impl Scale<f32> for ScaleSymlog {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Symlog
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<f32> {
+        self.domain().clone()
+    }
+
+    /// Get the domain max of the scale.
+    fn domain_max(&self) -> f32 {
+        self.domain.get(1).copied().unwrap_or(0_f32)
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, x: &f32) -> Result<f32, ChartError> {
+        self.check_domain()?;
+        let normalized = self.normalize(
+            self.transform(self.domain[0]),
+            self.transform(self.domain[1]),
+            self.transform(*x),
+        );
+        Ok(self.interpolate(self.range[0] as f32, self.range[1] as f32, normalized))
+    }
+
+    /// Get the domain value for the given range value.
+    fn invert(&self, range: f32) -> Result<f32, ChartError> {
+        self.check_domain()?;
+        let normalized = self.normalize(self.range[0] as f32, self.range[1] as f32, range);
+        let transformed = self.interpolate(
+            self.transform(self.domain[0]),
+            self.transform(self.domain[1]),
+            normalized,
+        );
+        Ok(self.untransform(transformed))
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        Some(0_f32)
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.range[0] as f32
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.range[1] as f32
+    }
+
+    /// Get the ticks for the scale.
+    /// The ticks land on zero and on the positive and negative powers of 10 outside the
+    /// linear part of the scale, thinned out when there are more than the tick count.
+    fn get_ticks(&self) -> Vec<f32> {
+        if self.check_domain().is_err() {
+            return Vec::new();
+        }
+        if let Some(values) = &self.tick_values {
+            return ticks_within_domain(values, &self.domain);
+        }
+        if self.tick_count == 0 {
+            return Vec::new();
+        }
+
+        let (start, end) = (
+            self.domain[0].min(self.domain[1]),
+            self.domain[0].max(self.domain[1]),
+        );
+        let has_zero = start <= 0_f32 && end >= 0_f32;
+        let negative = self.exponents_between((-end).max(0_f32), -start);
+        let positive = self.exponents_between(start.max(0_f32), end);
+
+        let count = negative.len() + positive.len() + has_zero as usize;
+        let step = count.div_ceil(self.tick_count.max(1)).max(1) as i32;
+        let kept = |exponent: &&i32| exponent.rem_euclid(step) == 0;
+
+        let mut ticks = negative
+            .iter()
+            .rev()
+            .filter(kept)
+            .map(|exponent| scaled_power(-1_f64, 10_f64, *exponent as f64) as f32)
+            .collect::<Vec<f32>>();
+        if has_zero {
+            ticks.push(0_f32);
+        }
+        ticks.extend(
+            positive
+                .iter()
+                .filter(kept)
+                .map(|exponent| scaled_power(1_f64, 10_f64, *exponent as f64) as f32),
+        );
+
+        if ticks.len() < 2 {
+            ticks = vec![start, end];
+        }
+        ticks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_both_signs_symmetrically() {
+        let scale = ScaleSymlog::new()
+            .set_domain(vec![-1_000_000_f32, 1_000_000_f32])
+            .set_range(vec![0, 600]);
+
+        assert_eq!(scale.scale(&0_f32).unwrap(), 300_f32);
+        let above = scale.scale(&1_000_f32).unwrap() - 300_f32;
+        let below = 300_f32 - scale.scale(&-1_000_f32).unwrap();
+        assert!((above - below).abs() < 1e-3);
+        assert_eq!(
+            scale.get_ticks(),
+            vec![
+                -1_000_000_f32,
+                -10_000_f32,
+                -100_f32,
+                -1_f32,
+                0_f32,
+                1_f32,
+                100_f32,
+                10_000_f32,
+                1_000_000_f32
+            ]
+        );
+    }
+
+    #[test]
+    fn constant_skips_linear_ticks() {
+        let scale = ScaleSymlog::new()
+            .set_domain(vec![0_f32, 10_000_f32])
+            .set_constant(50_f32);
+        assert_eq!(
+            scale.get_ticks(),
+            vec![0_f32, 100_f32, 1_000_f32, 10_000_f32]
+        );
+
+        let scale = ScaleSymlog::new()
+            .set_domain(vec![0_f32, 1_f32])
+            .set_constant(0_f32);
+        assert!(matches!(scale.scale(&1_f32), Err(ChartError::Scale(_))));
+    }
+
+    #[test]
+    fn empty_domain_has_zero_max() {
+        assert_eq!(ScaleSymlog::new().domain_max(), 0_f32);
+    }
+}
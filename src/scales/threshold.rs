@@ -0,0 +1,117 @@
+use crate::error::ChartError;
+
+/// The scale to divide a continuous domain at explicit breakpoints.
+/// The domain lists the breakpoints in ascending order and the range lists one more
+/// output than there are breakpoints: values below the first breakpoint are mapped
+/// to the first output, values from the last breakpoint on to the last output.
+#[derive(Debug)]
+pub struct ScaleThreshold<R> {
+    /// The breakpoints between the outputs, in ascending order.
+    domain: Vec<f32>,
+    /// The outputs the bins are mapped to.
+    range: Vec<R>,
+}
+
+impl<R> Default for ScaleThreshold<R> {
+    fn default() -> Self {
+        Self {
+            domain: Vec::new(),
+            range: Vec::new(),
+        }
+    }
+}
+
+impl<R> ScaleThreshold<R> {
+    /// Create a new threshold scale with default values.
+    pub fn new() -> Self {
+        ScaleThreshold::default()
+    }
+
+    /// Set the breakpoints of the scale, in ascending order.
+    pub fn set_domain(mut self, range: Vec<f32>) -> Self {
+        self.domain = range;
+        self
+    }
+
+    /// Get the breakpoints of the scale.
+    pub fn domain(&self) -> &Vec<f32> {
+        &self.domain
+    }
+
+    /// Set the outputs of the scale, one more than there are breakpoints.
+    pub fn set_range(mut self, range: Vec<R>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the outputs of the scale.
+    pub fn range(&self) -> &Vec<R> {
+        &self.range
+    }
+
+    /// Get the values that separate the bins, which are the breakpoints of the domain.
+    pub fn thresholds(&self) -> Vec<f32> {
+        self.domain.clone()
+    }
+
+    /// Get the output for the given domain value.
+    pub fn scale(&self, value: f32) -> Result<&R, ChartError> {
+        if self.range.len() != self.domain.len() + 1 {
+            return Err(ChartError::Scale(format!(
+                "a threshold scale with {} breakpoints needs {} outputs, got {}",
+                self.domain.len(),
+                self.domain.len() + 1,
+                self.range.len()
+            )));
+        }
+        if self.domain.windows(2).any(|pair| pair[0] > pair[1]) {
+            return Err(ChartError::Scale(String::from(
+                "the breakpoints of a threshold scale must be in ascending order",
+            )));
+        }
+        if value.is_nan() {
+            return Err(ChartError::Scale(String::from(
+                "NaN cannot be mapped by a threshold scale",
+            )));
+        }
+
+        let index = self.domain.partition_point(|threshold| *threshold <= value);
+        Ok(&self.range[index])
+    }
+
+    /// Get the domain limits of the bin that is mapped to the output with the given index.
+    /// The first and last bins are unbounded, so they start or end at infinity.
+    pub fn invert_extent(&self, index: usize) -> Option<(f32, f32)> {
+        if index >= self.range.len() || index > self.domain.len() {
+            return None;
+        }
+        let start = if index == 0 {
+            f32::NEG_INFINITY
+        } else {
+            self.domain[index - 1]
+        };
+        let end = self.domain.get(index).copied().unwrap_or(f32::INFINITY);
+
+        Some((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_values_between_breakpoints() {
+        let scale = ScaleThreshold::new()
+            .set_domain(vec![99_f32, 99.9_f32])
+            .set_range(vec!["breach", "warning", "ok"]);
+
+        assert_eq!(*scale.scale(98_f32).unwrap(), "breach");
+        assert_eq!(*scale.scale(99_f32).unwrap(), "warning");
+        assert_eq!(*scale.scale(99.95_f32).unwrap(), "ok");
+        assert_eq!(scale.invert_extent(0), Some((f32::NEG_INFINITY, 99_f32)));
+
+        let scale = scale.set_range(vec!["breach", "ok"]);
+        assert!(matches!(scale.scale(98_f32), Err(ChartError::Scale(_))));
+    }
+}
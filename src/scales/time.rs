@@ -0,0 +1,413 @@
+use crate::error::ChartError;
+use crate::scales::{Scale, ScaleType};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Weekday};
+
+/// The calendar units time ticks are aligned to.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// Return the approximate length of the unit in seconds.
+    fn seconds(&self) -> i64 {
+        match self {
+            TimeUnit::Second => 1,
+            TimeUnit::Minute => 60,
+            TimeUnit::Hour => 3_600,
+            TimeUnit::Day => 86_400,
+            TimeUnit::Week => 604_800,
+            TimeUnit::Month => 2_592_000,
+            TimeUnit::Year => 31_536_000,
+        }
+    }
+}
+
+/// The intervals between ticks, from the shortest to the longest.
+/// Intervals longer than a quarter are multiples of years chosen from the domain span.
+const TICK_INTERVALS: [(TimeUnit, i64); 17] = [
+    (TimeUnit::Second, 1),
+    (TimeUnit::Second, 5),
+    (TimeUnit::Second, 15),
+    (TimeUnit::Second, 30),
+    (TimeUnit::Minute, 1),
+    (TimeUnit::Minute, 5),
+    (TimeUnit::Minute, 15),
+    (TimeUnit::Minute, 30),
+    (TimeUnit::Hour, 1),
+    (TimeUnit::Hour, 3),
+    (TimeUnit::Hour, 6),
+    (TimeUnit::Hour, 12),
+    (TimeUnit::Day, 1),
+    (TimeUnit::Day, 2),
+    (TimeUnit::Week, 1),
+    (TimeUnit::Month, 1),
+    (TimeUnit::Month, 3),
+];
+
+/// The scale to represent dates and times.
+/// Ticks fall on calendar boundaries and are labeled with a matching strftime format.
+#[derive(Debug)]
+pub struct ScaleTime {
+    /// The domain limits of the dataset that the scale is going to represent.
+    domain: Vec<NaiveDateTime>,
+    /// The range limits of the drawable area on the chart.
+    range: Vec<isize>,
+    /// The amount of ticks to display.
+    tick_count: usize,
+    /// The dates to display ticks at, computed from the tick count when not set.
+    tick_values: Option<Vec<NaiveDateTime>>,
+    /// The strftime format of the tick labels, chosen from the tick interval when not set.
+    tick_format: Option<String>,
+}
+
+impl Default for ScaleTime {
+    fn default() -> Self {
+        Self {
+            domain: Vec::new(),
+            range: vec![0, 1],
+            tick_count: 10,
+            tick_values: None,
+            tick_format: None,
+        }
+    }
+}
+
+impl ScaleTime {
+    /// Create a new time scale with default values.
+    pub fn new() -> Self {
+        ScaleTime::default()
+    }
+
+    /// Set the domain limits for the scale.
+    pub fn set_domain(mut self, range: Vec<NaiveDateTime>) -> Self {
+        self.domain = range;
+        self
+    }
+
+    /// Get the domain limits of the scale.
+    pub fn domain(&self) -> &Vec<NaiveDateTime> {
+        &self.domain
+    }
+
+    /// Set the range limits for the scale.
+    pub fn set_range(mut self, range: Vec<isize>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Get the range limits of the scale.
+    pub fn range(&self) -> &Vec<isize> {
+        &self.range
+    }
+
+    /// Set the strftime format of the tick labels, e.g. `%Y-%m-%d`.
+    pub fn set_tick_format(mut self, format: &str) -> Self {
+        self.tick_format = Some(format.to_owned());
+        self
+    }
+
+    /// Set the approximate amount of ticks to display, 10 by default.
+    /// The ticks are still placed on calendar boundaries, so the actual amount may differ.
+    pub fn set_tick_count(mut self, tick_count: usize) -> Self {
+        self.tick_count = tick_count;
+        self
+    }
+
+    /// Set the exact dates to display ticks at instead of computing them.
+    /// Dates outside of the domain are not displayed.
+    pub fn set_tick_values(mut self, tick_values: Vec<NaiveDateTime>) -> Self {
+        self.tick_values = Some(tick_values);
+        self
+    }
+
+    /// Return the earliest and latest dates of the domain.
+    fn extent(&self) -> Result<(NaiveDateTime, NaiveDateTime), ChartError> {
+        match self.domain.as_slice() {
+            [start, end] => Ok((*start.min(end), *start.max(end))),
+            _ => Err(ChartError::Scale(format!(
+                "a time scale domain needs a start and an end, got {} values",
+                self.domain.len()
+            ))),
+        }
+    }
+
+    /// Choose the tick interval whose length is the closest to an even split of the domain.
+    fn tick_interval(&self) -> Result<(TimeUnit, i64), ChartError> {
+        let (start, end) = self.extent()?;
+        let span = (end - start).num_milliseconds() as f64 / 1_000_f64;
+        let target = span / self.tick_count.max(1) as f64;
+
+        let longer = TICK_INTERVALS
+            .iter()
+            .position(|(unit, step)| (unit.seconds() * step) as f64 >= target);
+        let interval = match longer {
+            Some(0) => TICK_INTERVALS[0],
+            Some(i) => {
+                let (shorter, longer) = (TICK_INTERVALS[i - 1], TICK_INTERVALS[i]);
+                let shorter_length = (shorter.0.seconds() * shorter.1) as f64;
+                let longer_length = (longer.0.seconds() * longer.1) as f64;
+                if target / shorter_length < longer_length / target {
+                    shorter
+                } else {
+                    longer
+                }
+            }
+            None => (TimeUnit::Year, Self::year_step(target)),
+        };
+        Ok(interval)
+    }
+
+    /// Return a 1, 2 or 5 multiple of a power of 10 years close to the target tick distance.
+    fn year_step(target: f64) -> i64 {
+        let years = target / TimeUnit::Year.seconds() as f64;
+        let power = 10_f64.powi(years.log10().floor() as i32);
+        let error = years / power;
+        let multiple = if error >= 50_f64.sqrt() {
+            10_f64
+        } else if error >= 10_f64.sqrt() {
+            5_f64
+        } else if error >= 2_f64.sqrt() {
+            2_f64
+        } else {
+            1_f64
+        };
+        ((multiple * power).round() as i64).max(1)
+    }
+
+    /// Return the strftime format that matches the tick interval.
+    fn default_tick_format(&self, unit: TimeUnit) -> &'static str {
+        let spans_days = self
+            .extent()
+            .map(|(start, end)| end - start > Duration::days(1))
+            .unwrap_or(false);
+
+        match unit {
+            TimeUnit::Second => "%H:%M:%S",
+            TimeUnit::Minute | TimeUnit::Hour if spans_days => "%b %d %H:%M",
+            TimeUnit::Minute | TimeUnit::Hour => "%H:%M",
+            TimeUnit::Day | TimeUnit::Week => "%b %d",
+            TimeUnit::Month => "%b %Y",
+            TimeUnit::Year => "%Y",
+        }
+    }
+}
+
+/// Round a date down to the start of the unit it falls in.
+fn floor(date: NaiveDateTime, unit: TimeUnit) -> NaiveDateTime {
+    let day = date.date();
+    let time = match unit {
+        TimeUnit::Second => day.and_hms_opt(date.hour(), date.minute(), date.second()),
+        TimeUnit::Minute => day.and_hms_opt(date.hour(), date.minute(), 0),
+        TimeUnit::Hour => day.and_hms_opt(date.hour(), 0, 0),
+        TimeUnit::Day | TimeUnit::Week => day.and_hms_opt(0, 0, 0),
+        TimeUnit::Month => NaiveDate::from_ymd_opt(date.year(), date.month(), 1)
+            .and_then(|day| day.and_hms_opt(0, 0, 0)),
+        TimeUnit::Year => {
+            NaiveDate::from_ymd_opt(date.year(), 1, 1).and_then(|day| day.and_hms_opt(0, 0, 0))
+        }
+    };
+    time.unwrap_or(date)
+}
+
+/// Return the start of the next unit after a date that is already rounded down.
+fn advance(date: NaiveDateTime, unit: TimeUnit) -> Option<NaiveDateTime> {
+    match unit {
+        TimeUnit::Second | TimeUnit::Minute | TimeUnit::Hour | TimeUnit::Day | TimeUnit::Week => {
+            let seconds = match unit {
+                TimeUnit::Week => TimeUnit::Day.seconds(),
+                _ => unit.seconds(),
+            };
+            date.checked_add_signed(Duration::seconds(seconds))
+        }
+        TimeUnit::Month if date.month() == 12 => {
+            NaiveDate::from_ymd_opt(date.year() + 1, 1, 1).and_then(|day| day.and_hms_opt(0, 0, 0))
+        }
+        TimeUnit::Month => NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+            .and_then(|day| day.and_hms_opt(0, 0, 0)),
+        TimeUnit::Year => {
+            NaiveDate::from_ymd_opt(date.year() + 1, 1, 1).and_then(|day| day.and_hms_opt(0, 0, 0))
+        }
+    }
+}
+
+/// Check whether a date that is already rounded down starts a tick interval.
+fn is_aligned(date: NaiveDateTime, unit: TimeUnit, step: i64) -> bool {
+    match unit {
+        TimeUnit::Second => date.second() as i64 % step == 0,
+        TimeUnit::Minute => date.minute() as i64 % step == 0,
+        TimeUnit::Hour => date.hour() as i64 % step == 0,
+        TimeUnit::Day => (date.day() as i64 - 1) % step == 0,
+        TimeUnit::Week => date.weekday() == Weekday::Mon,
+        TimeUnit::Month => date.month0() as i64 % step == 0,
+        TimeUnit::Year => (date.year() as i64).rem_euclid(step) == 0,
+    }
+}
+
+impl Scale<NaiveDateTime> for ScaleTime {
+    /// Get the type of the scale.
+    fn get_type(&self) -> ScaleType {
+        ScaleType::Time
+    }
+
+    /// Get the domain of the scale.
+    fn get_domain(&self) -> Vec<NaiveDateTime> {
+        self.domain().clone()
+    }
+
+    /// Get the domain max of the scale, in seconds since the Unix epoch.
+    fn domain_max(&self) -> f32 {
+        match (self.domain.last(), NaiveDate::from_ymd_opt(1970, 1, 1)) {
+            (Some(end), Some(epoch)) => epoch
+                .and_hms_opt(0, 0, 0)
+                .map(|epoch| (*end - epoch).num_seconds() as f32)
+                .unwrap_or(0_f32),
+            _ => 0_f32,
+        }
+    }
+
+    /// Get the range value for the given domain entry.
+    fn scale(&self, domain: &NaiveDateTime) -> Result<f32, ChartError> {
+        self.extent()?;
+        let (start, end) = (self.domain[0], self.domain[1]);
+        let span = (end - start).num_milliseconds() as f64;
+        let normalized = if span == 0_f64 {
+            0.5_f64
+        } else {
+            (*domain - start).num_milliseconds() as f64 / span
+        };
+        let a = self.range[0] as f64;
+        let b = self.range[1] as f64;
+
+        Ok(((b - a) * normalized + a) as f32)
+    }
+
+    /// Get the date for the given range value, rounded to the millisecond.
+    fn invert(&self, range: f32) -> Result<NaiveDateTime, ChartError> {
+        self.extent()?;
+        let (start, end) = (self.domain[0], self.domain[1]);
+        let a = self.range[0] as f64;
+        let b = self.range[1] as f64;
+        let normalized = if a == b {
+            0.5_f64
+        } else {
+            (range as f64 - a) / (b - a)
+        };
+        let offset = ((end - start).num_milliseconds() as f64 * normalized).round() as i64;
+
+        start
+            .checked_add_signed(Duration::milliseconds(offset))
+            .ok_or_else(|| {
+                ChartError::Scale(format!(
+                    "{} is outside of the dates a time scale can represent",
+                    range
+                ))
+            })
+    }
+
+    /// Get the bandwidth (if present).
+    fn bandwidth(&self) -> Option<f32> {
+        Some(0_f32)
+    }
+
+    /// Get the start range value.
+    fn range_start(&self) -> f32 {
+        self.range[0] as f32
+    }
+
+    /// Get the end range value.
+    fn range_end(&self) -> f32 {
+        self.range[1] as f32
+    }
+
+    /// Get the list of ticks that represent the scale on a chart axis.
+    fn get_ticks(&self) -> Vec<NaiveDateTime> {
+        let (start, end, (unit, step)) = match (self.extent(), self.tick_interval()) {
+            (Ok((start, end)), Ok(interval)) => (start, end, interval),
+            _ => return Vec::new(),
+        };
+        if let Some(values) = &self.tick_values {
+            return values
+                .iter()
+                .copied()
+                .filter(|date| *date >= start && *date <= end)
+                .collect();
+        }
+        if self.tick_count == 0 {
+            return Vec::new();
+        }
+
+        let mut ticks = Vec::new();
+        let mut tick = Some(floor(start, unit));
+        while let Some(date) = tick {
+            if date > end {
+                break;
+            }
+            if date >= start && is_aligned(date, unit, step) {
+                ticks.push(date);
+            }
+            tick = advance(date, unit);
+        }
+
+        ticks
+    }
+
+    /// Get the label of a tick, formatted according to the tick interval.
+    fn tick_label(&self, tick: &NaiveDateTime) -> Option<String> {
+        let format = match &self.tick_format {
+            Some(format) => format.as_str(),
+            None => self.default_tick_format(self.tick_interval().ok()?.0),
+        };
+        Some(tick.format(format).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|day| day.and_hms_opt(hour, 0, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn ticks_fall_on_calendar_boundaries() {
+        let scale = ScaleTime::new().set_domain(vec![date(2020, 1, 15, 0), date(2021, 1, 15, 0)]);
+        let ticks = scale.get_ticks();
+
+        assert_eq!(ticks.first(), Some(&date(2020, 2, 1, 0)));
+        assert_eq!(ticks.len(), 12);
+        assert_eq!(scale.tick_label(&ticks[0]).unwrap(), "Feb 2020");
+
+        let scale = ScaleTime::new().set_domain(vec![date(2020, 1, 1, 5), date(2020, 1, 2, 5)]);
+        let ticks = scale.get_ticks();
+
+        assert_eq!(ticks.first(), Some(&date(2020, 1, 1, 6)));
+        assert!(ticks
+            .windows(2)
+            .all(|pair| pair[1] - pair[0] == Duration::hours(3)));
+        assert_eq!(scale.tick_label(&ticks[0]).unwrap(), "06:00");
+    }
+
+    #[test]
+    fn maps_dates_linearly() {
+        let scale = ScaleTime::new()
+            .set_domain(vec![date(2020, 1, 1, 0), date(2020, 1, 11, 0)])
+            .set_range(vec![0, 100]);
+
+        assert_eq!(scale.scale(&date(2020, 1, 6, 0)).unwrap(), 50_f32);
+        assert_eq!(scale.invert(25_f32).unwrap(), date(2020, 1, 3, 12));
+        assert!(matches!(
+            ScaleTime::new().scale(&date(2020, 1, 1, 0)),
+            Err(ChartError::Scale(_))
+        ));
+    }
+}
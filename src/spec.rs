@@ -0,0 +1,991 @@
+use crate::views::View;
+use crate::{
+    AreaSeriesView, AxisPosition, BarLabelPosition, Chart, ChartError, Color, HorizontalBarView,
+    LineSeriesView, MarkerType, PointDatum, PointLabelPosition, Scale, ScaleBand, ScaleLinear,
+    ScaleLogarithmic, ScalePoint, ScalePow, ScaleSqrt, ScaleSymlog, ScatterView, VerticalBarView,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// A declarative description of a chart that can be stored in a JSON, TOML or
+/// YAML file and deserialized with serde.
+/// The scale ranges are inferred from the chart size and margins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChartSpec {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margins: Option<MarginsSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title_font_size: Option<usize>,
+    pub x_scale: ScaleSpec,
+    pub y_scale: ScaleSpec,
+    #[serde(default)]
+    pub axes: Vec<AxisSpec>,
+    #[serde(default)]
+    pub views: Vec<ViewSpec>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legend_position: Option<AxisPosition>,
+    /// The directory CSV paths are resolved against.
+    #[serde(skip)]
+    base_dir: Option<PathBuf>,
+}
+
+/// The space between the chart edges and the area the views are drawn in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MarginsSpec {
+    pub top: isize,
+    pub right: isize,
+    pub bottom: isize,
+    pub left: isize,
+}
+
+/// The scale of a chart dimension, identified by its `type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScaleSpec {
+    Linear {
+        domain: Vec<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nice: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        clamp: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        padding: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tick_count: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tick_values: Option<Vec<f32>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        breaks: Option<Vec<(f32, f32)>>,
+    },
+    Logarithmic {
+        domain: Vec<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        base: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        sub_ticks: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        nice: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        clamp: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        padding: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tick_count: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        tick_values: Option<Vec<f32>>,
+    },
+    Symlog {
+        domain: Vec<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        constant: Option<f32>,
+    },
+    Pow {
+        domain: Vec<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        exponent: Option<f32>,
+    },
+    Sqrt {
+        domain: Vec<f32>,
+    },
+    Band {
+        domain: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        inner_padding: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        outer_padding: Option<f32>,
+    },
+    Point {
+        domain: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        padding: Option<f32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        align: Option<f32>,
+    },
+}
+
+/// An axis of the chart. Top and bottom axes represent the x scale, left and
+/// right axes represent the y scale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AxisSpec {
+    pub position: AxisPosition,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_font_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_label_format: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_label_rotation: Option<isize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tick_label_font_size: Option<usize>,
+}
+
+/// A view of the chart, identified by its `type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ViewSpec {
+    /// Bars with the categories on a band x scale and the values on the y scale.
+    VerticalBar(BarViewSpec),
+    /// Bars with the values on the x scale and the categories on a band y scale.
+    HorizontalBar(BarViewSpec),
+    Line(PointViewSpec),
+    Scatter(PointViewSpec),
+    Area(PointViewSpec),
+}
+
+/// The options of a vertical or horizontal bar view.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BarViewSpec {
+    pub data: DataSpec,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_data_label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels_visible: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_position: Option<BarLabelPosition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_font_size: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_rounding_precision: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legend_font_size: Option<usize>,
+}
+
+/// The options of a line, scatter or area view.
+/// Area views draw a single series, so they ignore the keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PointViewSpec {
+    pub data: DataSpec,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keys: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_data_label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels_visible: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label_position: Option<PointLabelPosition>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker_type: Option<MarkerType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legend_font_size: Option<usize>,
+}
+
+/// The data of a view, either listed inline or read from the columns of a CSV file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DataSpec {
+    Inline(Vec<DatumSpec>),
+    Csv(CsvSpec),
+}
+
+/// A single datum. Bar views read the category and the value from `x` and `y`
+/// according to their orientation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DatumSpec {
+    pub x: ValueSpec,
+    pub y: ValueSpec,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+/// A reference to a CSV file with a header row and the names of the columns
+/// that hold the data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CsvSpec {
+    pub csv: PathBuf,
+    pub x: String,
+    pub y: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+}
+
+/// A value that is either a number or a text.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ValueSpec {
+    Number(f32),
+    Text(String),
+}
+
+/// A value that can be read from a `ValueSpec`.
+trait FromValue: Sized {
+    fn from_value(value: &ValueSpec) -> Result<Self, ChartError>;
+}
+
+impl FromValue for f32 {
+    fn from_value(value: &ValueSpec) -> Result<Self, ChartError> {
+        match value {
+            ValueSpec::Number(number) => Ok(*number),
+            ValueSpec::Text(text) => text
+                .trim()
+                .parse()
+                .map_err(|_| ChartError::Data(format!("`{}` is not a number", text))),
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &ValueSpec) -> Result<Self, ChartError> {
+        match value {
+            ValueSpec::Number(number) => Ok(number.to_string()),
+            ValueSpec::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+/// A datum of a line, scatter or area view built from a `DatumSpec`.
+struct SpecPoint<T, U> {
+    x: T,
+    y: U,
+    key: String,
+}
+
+impl<T: Clone, U: Clone> PointDatum<T, U> for SpecPoint<T, U> {
+    fn get_x(&self) -> T {
+        self.x.clone()
+    }
+
+    fn get_y(&self) -> U {
+        self.y.clone()
+    }
+
+    fn get_key(&self) -> String {
+        self.key.clone()
+    }
+}
+
+/// A scale built from a `ScaleSpec`.
+enum SpecScale {
+    Band(ScaleBand),
+    Point(ScalePoint),
+    Linear(ScaleLinear),
+    Logarithmic(ScaleLogarithmic),
+    Symlog(ScaleSymlog),
+    Pow(ScalePow),
+    Sqrt(ScaleSqrt),
+}
+
+/// A borrowed scale, split by the type of values it represents.
+/// Band and point scales are both borrowed as `Band`, as they represent categories.
+enum SpecScaleRef<'a> {
+    Band(&'a dyn Scale<String>),
+    Numeric(&'a dyn Scale<f32>),
+}
+
+impl SpecScale {
+    fn as_scale(&self) -> SpecScaleRef<'_> {
+        match self {
+            SpecScale::Band(scale) => SpecScaleRef::Band(scale),
+            SpecScale::Point(scale) => SpecScaleRef::Band(scale),
+            SpecScale::Linear(scale) => SpecScaleRef::Numeric(scale),
+            SpecScale::Logarithmic(scale) => SpecScaleRef::Numeric(scale),
+            SpecScale::Symlog(scale) => SpecScaleRef::Numeric(scale),
+            SpecScale::Pow(scale) => SpecScaleRef::Numeric(scale),
+            SpecScale::Sqrt(scale) => SpecScaleRef::Numeric(scale),
+        }
+    }
+}
+
+/// A line, scatter or area view over the given value types.
+enum PointView<'a, T: Display + Clone, U: Display + Clone> {
+    Line(LineSeriesView<'a, T, U>),
+    Scatter(ScatterView<'a, T, U>),
+    Area(AreaSeriesView<'a, T, U>),
+}
+
+impl<'a, T: Display + Clone, U: Display + Clone> PointView<'a, T, U> {
+    fn as_view(&self) -> &dyn View<'a> {
+        match self {
+            PointView::Line(view) => view,
+            PointView::Scatter(view) => view,
+            PointView::Area(view) => view,
+        }
+    }
+}
+
+/// A view built from a `ViewSpec`.
+/// The views are kept as concrete types so the chart can borrow them.
+enum SpecView<'a> {
+    VerticalBar(VerticalBarView<'a>),
+    HorizontalBar(HorizontalBarView<'a>),
+    NumericNumeric(PointView<'a, f32, f32>),
+    BandNumeric(PointView<'a, String, f32>),
+    NumericBand(PointView<'a, f32, String>),
+    BandBand(PointView<'a, String, String>),
+}
+
+impl<'a> SpecView<'a> {
+    fn as_view(&self) -> &dyn View<'a> {
+        match self {
+            SpecView::VerticalBar(view) => view,
+            SpecView::HorizontalBar(view) => view,
+            SpecView::NumericNumeric(view) => view.as_view(),
+            SpecView::BandNumeric(view) => view.as_view(),
+            SpecView::NumericBand(view) => view.as_view(),
+            SpecView::BandBand(view) => view.as_view(),
+        }
+    }
+}
+
+impl ChartSpec {
+    /// Create a spec for a chart with default sizes over the given scales,
+    /// without any axes or views.
+    pub fn new(x_scale: ScaleSpec, y_scale: ScaleSpec) -> Self {
+        Self {
+            width: None,
+            height: None,
+            margins: None,
+            title: None,
+            title_font_size: None,
+            x_scale,
+            y_scale,
+            axes: Vec::new(),
+            views: Vec::new(),
+            legend_position: None,
+            base_dir: None,
+        }
+    }
+
+    /// Set the directory relative CSV paths are resolved against.
+    /// Defaults to the current working directory.
+    pub fn set_base_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.base_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Build the chart described by the spec and pass it to `render`.
+    /// The chart borrows the scales and views built from the spec, so it is
+    /// only available inside the closure.
+    pub fn render<R, F>(&self, render: F) -> Result<R, ChartError>
+    where
+        F: FnOnce(Chart<'_>) -> Result<R, ChartError>,
+    {
+        let mut chart = Chart::new();
+        if let Some(width) = self.width {
+            chart = chart.set_width(width);
+        }
+        if let Some(height) = self.height {
+            chart = chart.set_height(height);
+        }
+        if let Some(margins) = &self.margins {
+            chart = chart.set_margins(margins.top, margins.right, margins.bottom, margins.left);
+        }
+        if let Some(title) = &self.title {
+            chart = chart.add_title(title.clone());
+        }
+        if let Some(size) = self.title_font_size {
+            chart = chart.set_title_font_size(size);
+        }
+
+        let x_scale = self.x_scale.build(vec![0, chart.get_view_width()], false)?;
+        let y_scale = self.y_scale.build(vec![chart.get_view_height(), 0], true)?;
+
+        let views = self
+            .views
+            .iter()
+            .map(|view| view.build(&x_scale, &y_scale, self.base_dir.as_deref()))
+            .collect::<Result<Vec<SpecView>, ChartError>>()?;
+        for view in views.iter() {
+            chart = chart.add_view(view.as_view());
+        }
+
+        for axis in self.axes.iter() {
+            chart = axis.apply(chart, &x_scale, &y_scale)?;
+        }
+        if let Some(position) = self.legend_position {
+            chart = chart.add_legend_at(position);
+        }
+
+        render(chart)
+    }
+
+    /// Render the chart described by the spec as an SVG document string.
+    pub fn to_svg_string(&self) -> Result<String, ChartError> {
+        self.render(|chart| chart.to_svg_string())
+    }
+
+    /// Save the chart described by the spec to a file.
+    /// The format is chosen based on the file extension, as in `Chart::save`.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), ChartError> {
+        self.render(|chart| chart.save(path))
+    }
+}
+
+impl ScaleSpec {
+    /// Build the scale over the given range.
+    /// Band and point scales on the vertical dimension list their categories top to bottom.
+    fn build(&self, range: Vec<isize>, vertical: bool) -> Result<SpecScale, ChartError> {
+        match self {
+            ScaleSpec::Linear {
+                domain,
+                nice,
+                clamp,
+                padding,
+                tick_count,
+                tick_values,
+                breaks,
+            } => {
+                let mut scale = ScaleLinear::new()
+                    .set_domain(Self::check_domain(domain)?)
+                    .set_range(range)
+                    .set_padding(padding.unwrap_or(0_f32))
+                    .clamp(clamp.unwrap_or(false));
+                if let Some(tick_count) = tick_count {
+                    scale = scale.set_tick_count(*tick_count);
+                }
+                if *nice == Some(true) {
+                    scale = scale.nice();
+                }
+                if let Some(tick_values) = tick_values {
+                    scale = scale.set_tick_values(tick_values.clone());
+                }
+                if let Some(breaks) = breaks {
+                    scale = scale.set_breaks(breaks.clone());
+                }
+                Ok(SpecScale::Linear(scale))
+            }
+            ScaleSpec::Logarithmic {
+                domain,
+                base,
+                sub_ticks,
+                nice,
+                clamp,
+                padding,
+                tick_count,
+                tick_values,
+            } => {
+                let mut scale = ScaleLogarithmic::new()
+                    .set_domain(Self::check_domain(domain)?)
+                    .set_range(range)
+                    .set_padding(padding.unwrap_or(0_f32))
+                    .clamp(clamp.unwrap_or(false));
+                if let Some(base) = base {
+                    scale = scale.set_base(*base);
+                }
+                if let Some(sub_ticks) = sub_ticks {
+                    scale = scale.set_sub_ticks(*sub_ticks);
+                }
+                if let Some(tick_count) = tick_count {
+                    scale = scale.set_tick_count(*tick_count);
+                }
+                if *nice == Some(true) {
+                    scale = scale.nice();
+                }
+                if let Some(tick_values) = tick_values {
+                    scale = scale.set_tick_values(tick_values.clone());
+                }
+                Ok(SpecScale::Logarithmic(scale))
+            }
+            ScaleSpec::Symlog { domain, constant } => {
+                let mut scale = ScaleSymlog::new()
+                    .set_domain(Self::check_domain(domain)?)
+                    .set_range(range);
+                if let Some(constant) = constant {
+                    scale = scale.set_constant(*constant);
+                }
+                Ok(SpecScale::Symlog(scale))
+            }
+            ScaleSpec::Pow { domain, exponent } => {
+                let mut scale = ScalePow::new()
+                    .set_domain(Self::check_domain(domain)?)
+                    .set_range(range);
+                if let Some(exponent) = exponent {
+                    scale = scale.set_exponent(*exponent);
+                }
+                Ok(SpecScale::Pow(scale))
+            }
+            ScaleSpec::Sqrt { domain } => Ok(SpecScale::Sqrt(
+                ScaleSqrt::new()
+                    .set_domain(Self::check_domain(domain)?)
+                    .set_range(range),
+            )),
+            ScaleSpec::Band {
+                domain,
+                inner_padding,
+                outer_padding,
+            } => {
+                let range = if vertical {
+                    range.into_iter().rev().collect()
+                } else {
+                    range
+                };
+                let mut scale = ScaleBand::new().set_domain(domain.clone()).set_range(range);
+                if let Some(padding) = inner_padding {
+                    scale = scale.set_inner_padding(*padding);
+                }
+                if let Some(padding) = outer_padding {
+                    scale = scale.set_outer_padding(*padding);
+                }
+                Ok(SpecScale::Band(scale))
+            }
+            ScaleSpec::Point {
+                domain,
+                padding,
+                align,
+            } => {
+                let range = if vertical {
+                    range.into_iter().rev().collect()
+                } else {
+                    range
+                };
+                let mut scale = ScalePoint::new()
+                    .set_domain(domain.clone())
+                    .set_range(range);
+                if let Some(padding) = padding {
+                    scale = scale.set_padding(*padding);
+                }
+                if let Some(align) = align {
+                    scale = scale.set_align(*align);
+                }
+                Ok(SpecScale::Point(scale))
+            }
+        }
+    }
+
+    fn check_domain(domain: &[f32]) -> Result<Vec<f32>, ChartError> {
+        if domain.len() != 2 {
+            return Err(ChartError::Scale(format!(
+                "a continuous scale domain needs a start and an end, got {} values",
+                domain.len()
+            )));
+        }
+        Ok(domain.to_vec())
+    }
+}
+
+impl AxisSpec {
+    /// Add the axis to the chart along with its label and tick label options.
+    fn apply<'a>(
+        &self,
+        chart: Chart<'a>,
+        x_scale: &'a SpecScale,
+        y_scale: &'a SpecScale,
+    ) -> Result<Chart<'a>, ChartError> {
+        let mut chart = match (self.position, x_scale.as_scale(), y_scale.as_scale()) {
+            (AxisPosition::Top, SpecScaleRef::Band(scale), _) => chart.add_axis_top(scale),
+            (AxisPosition::Top, SpecScaleRef::Numeric(scale), _) => chart.add_axis_top(scale),
+            (AxisPosition::Bottom, SpecScaleRef::Band(scale), _) => chart.add_axis_bottom(scale),
+            (AxisPosition::Bottom, SpecScaleRef::Numeric(scale), _) => chart.add_axis_bottom(scale),
+            (AxisPosition::Left, _, SpecScaleRef::Band(scale)) => chart.add_axis_left(scale),
+            (AxisPosition::Left, _, SpecScaleRef::Numeric(scale)) => chart.add_axis_left(scale),
+            (AxisPosition::Right, _, SpecScaleRef::Band(scale)) => chart.add_axis_right(scale),
+            (AxisPosition::Right, _, SpecScaleRef::Numeric(scale)) => chart.add_axis_right(scale),
+        };
+
+        if let Some(label) = &self.label {
+            chart = match self.position {
+                AxisPosition::Top => chart.add_top_axis_label(label)?,
+                AxisPosition::Right => chart.add_right_axis_label(label)?,
+                AxisPosition::Bottom => chart.add_bottom_axis_label(label)?,
+                AxisPosition::Left => chart.add_left_axis_label(label)?,
+            };
+        }
+        if let Some(size) = self.label_font_size {
+            chart = match self.position {
+                AxisPosition::Top => chart.set_top_axis_label_font_size(size)?,
+                AxisPosition::Right => chart.set_right_axis_label_font_size(size)?,
+                AxisPosition::Bottom => chart.set_bottom_axis_label_font_size(size)?,
+                AxisPosition::Left => chart.set_left_axis_label_font_size(size)?,
+            };
+        }
+        if let Some(format) = &self.tick_label_format {
+            chart = match self.position {
+                AxisPosition::Top => chart.set_top_axis_tick_label_format(format),
+                AxisPosition::Right => chart.set_right_axis_tick_label_format(format),
+                AxisPosition::Bottom => chart.set_bottom_axis_tick_label_format(format),
+                AxisPosition::Left => chart.set_left_axis_tick_label_format(format),
+            };
+        }
+        if let Some(rotation) = self.tick_label_rotation {
+            chart = match self.position {
+                AxisPosition::Top => chart.set_top_axis_tick_label_rotation(rotation),
+                AxisPosition::Right => chart.set_right_axis_tick_label_rotation(rotation),
+                AxisPosition::Bottom => chart.set_bottom_axis_tick_label_rotation(rotation),
+                AxisPosition::Left => chart.set_left_axis_tick_label_rotation(rotation),
+            };
+        }
+        if let Some(size) = self.tick_label_font_size {
+            chart = match self.position {
+                AxisPosition::Top => chart.set_top_axis_tick_label_font_size(size),
+                AxisPosition::Right => chart.set_right_axis_tick_label_font_size(size),
+                AxisPosition::Bottom => chart.set_bottom_axis_tick_label_font_size(size),
+                AxisPosition::Left => chart.set_left_axis_tick_label_font_size(size),
+            };
+        }
+
+        Ok(chart)
+    }
+}
+
+impl ViewSpec {
+    /// Build the view over the chart scales and load its data.
+    fn build<'a>(
+        &self,
+        x_scale: &'a SpecScale,
+        y_scale: &'a SpecScale,
+        base_dir: Option<&Path>,
+    ) -> Result<SpecView<'a>, ChartError> {
+        match self {
+            ViewSpec::VerticalBar(spec) => match (x_scale.as_scale(), y_scale.as_scale()) {
+                (SpecScaleRef::Band(x), SpecScaleRef::Numeric(y)) => {
+                    let data = spec
+                        .data
+                        .load(base_dir)?
+                        .iter()
+                        .map(|datum| {
+                            Ok((
+                                String::from_value(&datum.x)?,
+                                f32::from_value(&datum.y)?,
+                                datum.key.clone().unwrap_or_default(),
+                            ))
+                        })
+                        .collect::<Result<Vec<(String, f32, String)>, ChartError>>()?;
+                    let mut view = VerticalBarView::new().set_x_scale(x).set_y_scale(y);
+                    if let Some(keys) = &spec.keys {
+                        view = view.set_keys(keys.clone());
+                    }
+                    if let Some(colors) = &spec.colors {
+                        view = view.set_colors(colors_from_strings(colors));
+                    }
+                    if let Some(label) = &spec.custom_data_label {
+                        view = view.set_custom_data_label(label.clone());
+                    }
+                    if let Some(visible) = spec.labels_visible {
+                        view = view.set_label_visibility(visible);
+                    }
+                    if let Some(position) = spec.label_position {
+                        view = view.set_label_position(position);
+                    }
+                    if let Some(size) = spec.label_font_size {
+                        view = view.set_label_font_size(size);
+                    }
+                    if let Some(precision) = spec.label_rounding_precision {
+                        view = view.set_label_rounding_precision(precision);
+                    }
+                    if let Some(size) = spec.legend_font_size {
+                        view = view.set_legend_font_size(size);
+                    }
+                    Ok(SpecView::VerticalBar(view.load_data(&data)?))
+                }
+                _ => Err(ChartError::Scale(
+                    "a vertical bar view needs a band x scale and a linear or logarithmic y scale"
+                        .to_string(),
+                )),
+            },
+            ViewSpec::HorizontalBar(spec) => match (x_scale.as_scale(), y_scale.as_scale()) {
+                (SpecScaleRef::Numeric(x), SpecScaleRef::Band(y)) => {
+                    let data = spec
+                        .data
+                        .load(base_dir)?
+                        .iter()
+                        .map(|datum| {
+                            Ok((
+                                String::from_value(&datum.y)?,
+                                f32::from_value(&datum.x)?,
+                                datum.key.clone().unwrap_or_default(),
+                            ))
+                        })
+                        .collect::<Result<Vec<(String, f32, String)>, ChartError>>()?;
+                    let mut view = HorizontalBarView::new().set_x_scale(x).set_y_scale(y);
+                    if let Some(keys) = &spec.keys {
+                        view = view.set_keys(keys.clone());
+                    }
+                    if let Some(colors) = &spec.colors {
+                        view = view.set_colors(colors_from_strings(colors));
+                    }
+                    if let Some(label) = &spec.custom_data_label {
+                        view = view.set_custom_data_label(label.clone());
+                    }
+                    if let Some(visible) = spec.labels_visible {
+                        view = view.set_label_visibility(visible);
+                    }
+                    if let Some(position) = spec.label_position {
+                        view = view.set_label_position(position);
+                    }
+                    if let Some(size) = spec.label_font_size {
+                        view = view.set_label_font_size(size);
+                    }
+                    if let Some(precision) = spec.label_rounding_precision {
+                        view = view.set_label_rounding_precision(precision);
+                    }
+                    if let Some(size) = spec.legend_font_size {
+                        view = view.set_legend_font_size(size);
+                    }
+                    Ok(SpecView::HorizontalBar(view.load_data(&data)?))
+                }
+                _ => Err(ChartError::Scale(
+                    "a horizontal bar view needs a linear or logarithmic x scale and a band y scale"
+                        .to_string(),
+                )),
+            },
+            ViewSpec::Line(spec) | ViewSpec::Scatter(spec) | ViewSpec::Area(spec) => {
+                let data = spec.data.load(base_dir)?;
+                Ok(match (x_scale.as_scale(), y_scale.as_scale()) {
+                    (SpecScaleRef::Numeric(x), SpecScaleRef::Numeric(y)) => {
+                        SpecView::NumericNumeric(self.build_point_view(spec, x, y, &data)?)
+                    }
+                    (SpecScaleRef::Band(x), SpecScaleRef::Numeric(y)) => {
+                        SpecView::BandNumeric(self.build_point_view(spec, x, y, &data)?)
+                    }
+                    (SpecScaleRef::Numeric(x), SpecScaleRef::Band(y)) => {
+                        SpecView::NumericBand(self.build_point_view(spec, x, y, &data)?)
+                    }
+                    (SpecScaleRef::Band(x), SpecScaleRef::Band(y)) => {
+                        SpecView::BandBand(self.build_point_view(spec, x, y, &data)?)
+                    }
+                })
+            }
+        }
+    }
+
+    /// Build a line, scatter or area view over scales of the given value types.
+    fn build_point_view<'a, T, U>(
+        &self,
+        spec: &PointViewSpec,
+        x_scale: &'a dyn Scale<T>,
+        y_scale: &'a dyn Scale<U>,
+        data: &[DatumSpec],
+    ) -> Result<PointView<'a, T, U>, ChartError>
+    where
+        T: Display + Clone + FromValue,
+        U: Display + Clone + FromValue,
+    {
+        let data = data
+            .iter()
+            .map(|datum| {
+                Ok(SpecPoint {
+                    x: T::from_value(&datum.x)?,
+                    y: U::from_value(&datum.y)?,
+                    key: datum.key.clone().unwrap_or_default(),
+                })
+            })
+            .collect::<Result<Vec<SpecPoint<T, U>>, ChartError>>()?;
+        let colors = spec
+            .colors
+            .as_ref()
+            .map(|colors| colors_from_strings(colors));
+
+        match self {
+            ViewSpec::Line(_) => {
+                let mut view = LineSeriesView::new()
+                    .set_x_scale(x_scale)
+                    .set_y_scale(y_scale);
+                if let Some(keys) = &spec.keys {
+                    view = view.set_keys(keys.clone());
+                }
+                if let Some(colors) = colors {
+                    view = view.set_colors(colors);
+                }
+                if let Some(label) = &spec.custom_data_label {
+                    view = view.set_custom_data_label(label.clone());
+                }
+                if let Some(visible) = spec.labels_visible {
+                    view = view.set_label_visibility(visible);
+                }
+                if let Some(position) = spec.label_position {
+                    view = view.set_label_position(position);
+                }
+                if let Some(marker_type) = spec.marker_type {
+                    view = view.set_marker_type(marker_type);
+                }
+                if let Some(size) = spec.legend_font_size {
+                    view = view.set_legend_font_size(size);
+                }
+                Ok(PointView::Line(view.load_data(&data)?))
+            }
+            ViewSpec::Scatter(_) => {
+                let mut view = ScatterView::new().set_x_scale(x_scale).set_y_scale(y_scale);
+                if let Some(keys) = &spec.keys {
+                    view = view.set_keys(keys.clone());
+                }
+                if let Some(colors) = colors {
+                    view = view.set_colors(colors);
+                }
+                if let Some(label) = &spec.custom_data_label {
+                    view = view.set_custom_data_label(label.clone());
+                }
+                if let Some(visible) = spec.labels_visible {
+                    view = view.set_label_visibility(visible);
+                }
+                if let Some(position) = spec.label_position {
+                    view = view.set_label_position(position);
+                }
+                if let Some(marker_type) = spec.marker_type {
+                    view = view.set_marker_type(marker_type);
+                }
+                if let Some(size) = spec.legend_font_size {
+                    view = view.set_legend_font_size(size);
+                }
+                Ok(PointView::Scatter(view.load_data(&data)?))
+            }
+            _ => {
+                let mut view = AreaSeriesView::new()
+                    .set_x_scale(x_scale)
+                    .set_y_scale(y_scale);
+                if let Some(colors) = colors {
+                    view = view.set_colors(colors);
+                }
+                if let Some(label) = &spec.custom_data_label {
+                    view = view.set_custom_data_label(label.clone());
+                }
+                if let Some(visible) = spec.labels_visible {
+                    view = view.set_label_visibility(visible);
+                }
+                if let Some(position) = spec.label_position {
+                    view = view.set_label_position(position);
+                }
+                if let Some(marker_type) = spec.marker_type {
+                    view = view.set_marker_type(marker_type);
+                }
+                if let Some(size) = spec.legend_font_size {
+                    view = view.set_legend_font_size(size);
+                }
+                Ok(PointView::Area(view.load_data(&data)?))
+            }
+        }
+    }
+}
+
+impl DataSpec {
+    /// Return the data listed inline or read from the CSV file.
+    fn load(&self, base_dir: Option<&Path>) -> Result<Vec<DatumSpec>, ChartError> {
+        match self {
+            DataSpec::Inline(data) => Ok(data.clone()),
+            DataSpec::Csv(spec) => spec.load(base_dir),
+        }
+    }
+}
+
+impl CsvSpec {
+    /// Read the data columns of the CSV file.
+    fn load(&self, base_dir: Option<&Path>) -> Result<Vec<DatumSpec>, ChartError> {
+        let path = match base_dir {
+            Some(dir) => dir.join(&self.csv),
+            None => self.csv.clone(),
+        };
+        let csv_error = |error: csv::Error| {
+            ChartError::Data(format!("cannot read {}: {}", path.display(), error))
+        };
+        let mut reader = csv::Reader::from_path(&path).map_err(csv_error)?;
+        let headers = reader.headers().map_err(csv_error)?.clone();
+        let column = |name: &str| {
+            headers
+                .iter()
+                .position(|header| header == name)
+                .ok_or_else(|| {
+                    ChartError::Data(format!("{} has no `{}` column", path.display(), name))
+                })
+        };
+        let x = column(&self.x)?;
+        let y = column(&self.y)?;
+        let key = self.key.as_deref().map(column).transpose()?;
+
+        let mut data = Vec::new();
+        for record in reader.records() {
+            let record = record.map_err(csv_error)?;
+            let field = |index: usize| record.get(index).unwrap_or_default().to_string();
+            data.push(DatumSpec {
+                x: ValueSpec::Text(field(x)),
+                y: ValueSpec::Text(field(y)),
+                key: key.map(field),
+            });
+        }
+        Ok(data)
+    }
+}
+
+fn colors_from_strings(colors: &[String]) -> Vec<Color> {
+    Color::from_vec_of_hex_strings(colors.iter().map(String::as_str).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_chart_from_json_spec() {
+        let spec: ChartSpec = serde_json::from_str(
+            r#"{
+                "title": "Spec",
+                "x_scale": { "type": "band", "domain": ["A", "B"] },
+                "y_scale": { "type": "linear", "domain": [0, 100] },
+                "axes": [
+                    { "position": "bottom", "label": "Category" },
+                    { "position": "left", "tick_label_format": ".0f" }
+                ],
+                "views": [
+                    {
+                        "type": "vertical_bar",
+                        "data": [{ "x": "A", "y": 20 }, { "x": "B", "y": 80 }]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let svg = spec.to_svg_string().unwrap();
+
+        assert!(svg.contains("Spec"));
+        assert!(svg.contains("Category"));
+        assert!(svg.contains("data-category=\"B\""));
+    }
+
+    #[test]
+    fn bar_view_requires_band_scale() {
+        let spec: ChartSpec = serde_json::from_str(
+            r#"{
+                "x_scale": { "type": "linear", "domain": [0, 1] },
+                "y_scale": { "type": "linear", "domain": [0, 1] },
+                "views": [{ "type": "vertical_bar", "data": [] }]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(spec.to_svg_string(), Err(ChartError::Scale(_))));
+    }
+
+    #[test]
+    fn nice_domain_follows_tick_count() {
+        let spec: ScaleSpec = serde_json::from_str(
+            r#"{ "type": "linear", "domain": [0, 130], "nice": true, "tick_count": 5 }"#,
+        )
+        .unwrap();
+
+        match spec.build(vec![0, 100], false).unwrap() {
+            SpecScale::Linear(scale) => assert_eq!(scale.domain(), &vec![0_f32, 140_f32]),
+            _ => panic!("expected a linear scale"),
+        }
+    }
+
+    #[test]
+    fn view_rejects_unknown_field() {
+        let spec = serde_json::from_str::<ChartSpec>(
+            r#"{
+                "x_scale": { "type": "band", "domain": ["A"] },
+                "y_scale": { "type": "linear", "domain": [0, 1] },
+                "views": [{ "type": "vertical_bar", "data": [], "label_postion": "center" }]
+            }"#,
+        );
+
+        assert!(spec.is_err());
+    }
+}
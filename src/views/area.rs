@@ -1,14 +1,14 @@
+use crate::backend::{Backend, GroupOptions};
 use crate::colors::Color;
 use crate::components::area::AreaSeries;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
 use crate::components::scatter::{MarkerType, PointLabelPosition, ScatterPoint};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::views::datum::PointDatum;
 use crate::views::View;
 use crate::Scale;
 use std::fmt::Display;
-use svg::node::element::Group;
-use svg::node::Node;
 
 /// A View that represents data as a scatter plot.
 pub struct AreaSeriesView<'a, T: Display + Clone, U: Display + Clone> {
@@ -46,13 +46,13 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
     }
 
     /// Set the scale for the X dimension.
-    pub fn set_x_scale(mut self, scale: &'a impl Scale<T>) -> Self {
+    pub fn set_x_scale(mut self, scale: &'a dyn Scale<T>) -> Self {
         self.x_scale = Some(scale);
         self
     }
 
     /// Set the scale for the Y dimension.
-    pub fn set_y_scale(mut self, scale: &'a impl Scale<U>) -> Self {
+    pub fn set_y_scale(mut self, scale: &'a dyn Scale<U>) -> Self {
         self.y_scale = Some(scale);
         self
     }
@@ -97,21 +97,21 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
     }
 
     /// Load and process a dataset of BarDatum points.
-    pub fn load_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, String> {
+    pub fn load_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, ChartError> {
         match self.x_scale {
             Some(_) => {}
             _ => {
-                return Err(
+                return Err(ChartError::Scale(
                     "Please provide a scale for the X dimension before loading data".to_string(),
-                )
+                ))
             }
         }
         match self.y_scale {
             Some(_) => {}
             _ => {
-                return Err(
+                return Err(ChartError::Scale(
                     "Please provide a scale for the Y dimension before loading data".to_string(),
-                )
+                ))
             }
         }
 
@@ -134,9 +134,9 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
         let mut points = data
             .iter()
             .map(|datum| {
-                let scaled_x = self.x_scale.unwrap().scale(&datum.get_x());
-                let scaled_y = self.y_scale.unwrap().scale(&datum.get_y());
-                ScatterPoint::new(
+                let scaled_x = self.x_scale.unwrap().scale(&datum.get_x())?;
+                let scaled_y = self.y_scale.unwrap().scale(&datum.get_y())?;
+                Ok(ScatterPoint::new(
                     scaled_x + x_bandwidth_offset,
                     scaled_y + y_bandwidth_offset,
                     self.marker_type,
@@ -147,9 +147,9 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
                     self.labels_visible,
                     true,
                     self.colors[0].as_hex(),
-                )
+                ))
             })
-            .collect::<Vec<ScatterPoint<T, U>>>();
+            .collect::<Result<Vec<ScatterPoint<T, U>>, ChartError>>()?;
 
         let y_origin = {
             if self.y_scale.unwrap().is_range_reversed() {
@@ -158,10 +158,16 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
                 self.y_scale.unwrap().range_end()
             }
         };
-        let first = data.first().unwrap();
-        let last = data.last().unwrap();
+        let (first, last) = match (data.first(), data.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => {
+                return Err(ChartError::Data(
+                    "Cannot draw an area series without any data points".to_string(),
+                ))
+            }
+        };
         points.push(ScatterPoint::new(
-            self.x_scale.unwrap().scale(&last.get_x()) + x_bandwidth_offset,
+            self.x_scale.unwrap().scale(&last.get_x())? + x_bandwidth_offset,
             y_origin,
             self.marker_type,
             5,
@@ -173,7 +179,7 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
             "#fff".to_string(),
         ));
         points.push(ScatterPoint::new(
-            self.x_scale.unwrap().scale(&first.get_x()) + x_bandwidth_offset,
+            self.x_scale.unwrap().scale(&first.get_x())? + x_bandwidth_offset,
             y_origin,
             self.marker_type,
             5,
@@ -193,16 +199,17 @@ impl<'a, T: Display + Clone, U: Display + Clone> AreaSeriesView<'a, T, U> {
 }
 
 impl<'a, T: Display + Clone, U: Display + Clone> View<'a> for AreaSeriesView<'a, T, U> {
-    /// Generate the SVG representation of the view.
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new();
+    /// Draw the view on the given backend.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        backend.begin_group(&GroupOptions::new());
 
         for entry in self.entries.iter() {
-            let child_svg = entry.to_svg()?;
-            group.append(child_svg);
+            entry.draw(backend)?;
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 
     /// Return the legend entries that this view represents.
@@ -1,3 +1,6 @@
+#[cfg(feature = "chrono")]
+use chrono::NaiveDateTime;
+
 /// A trait that defines interaction with a data point used in a bar chart.
 /// This provides greater flexibility in using different data sources as one
 /// can simply implement this trait and be able to use that data in a bar chart.
@@ -26,6 +29,13 @@ pub trait PointDatum<T, U> {
     /// only a single type of data (just return an empty string), but is required
     /// in a chart that represents multiple categories of points.
     fn get_key(&self) -> String;
+
+    /// Return the value the datum is colored by in a view with a color scale, none by default.
+    /// Views color data without a color value by its Y value when it is a number,
+    /// and by the color of its key otherwise.
+    fn get_color_value(&self) -> Option<f32> {
+        None
+    }
 }
 
 impl BarDatum for (f32, &str) {
@@ -279,6 +289,43 @@ impl PointDatum<String, f32> for (String, isize) {
         String::new()
     }
 }
+
+impl PointDatum<f32, f32> for (f32, f32, f32) {
+    fn get_x(&self) -> f32 {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn get_color_value(&self) -> Option<f32> {
+        Some(self.2)
+    }
+}
+
+impl PointDatum<String, String> for (String, String, f32) {
+    fn get_x(&self) -> String {
+        self.0.clone()
+    }
+
+    fn get_y(&self) -> String {
+        self.1.clone()
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+
+    fn get_color_value(&self) -> Option<f32> {
+        Some(self.2)
+    }
+}
+
 impl PointDatum<f64, f64> for (f64, f64) {
     fn get_x(&self) -> f64 {
         self.0
@@ -292,3 +339,48 @@ impl PointDatum<f64, f64> for (f64, f64) {
         String::new()
     }
 }
+
+#[cfg(feature = "chrono")]
+impl PointDatum<NaiveDateTime, f32> for (NaiveDateTime, f32) {
+    fn get_x(&self) -> NaiveDateTime {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::new()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PointDatum<NaiveDateTime, f32> for (NaiveDateTime, f32, &str) {
+    fn get_x(&self) -> NaiveDateTime {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        String::from(self.2)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl PointDatum<NaiveDateTime, f32> for (NaiveDateTime, f32, String) {
+    fn get_x(&self) -> NaiveDateTime {
+        self.0
+    }
+
+    fn get_y(&self) -> f32 {
+        self.1
+    }
+
+    fn get_key(&self) -> String {
+        self.2.clone()
+    }
+}
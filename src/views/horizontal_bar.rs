@@ -1,14 +1,14 @@
+use crate::backend::{Backend, GroupOptions};
 use crate::chart::Orientation;
 use crate::colors::Color;
 use crate::components::bar::{Bar, BarBlock, BarLabelPosition};
 use crate::components::legend::{LegendEntry, LegendMarkerType};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::scales::ScaleType;
 use crate::views::View;
-use crate::{BarDatum, Scale};
+use crate::{BarDatum, Scale, ScaleColor};
 use std::collections::HashMap;
-use svg::node::element::Group;
-use svg::node::Node;
 
 /// A View that represents data as horizontal bars.
 pub struct HorizontalBarView<'a> {
@@ -20,6 +20,7 @@ pub struct HorizontalBarView<'a> {
     keys: Vec<String>,
     colors: Vec<Color>,
     color_map: HashMap<String, String>,
+    color_scale: Option<&'a ScaleColor>,
     x_scale: Option<&'a dyn Scale<f32>>,
     y_scale: Option<&'a dyn Scale<String>>,
     custom_data_label: String,
@@ -37,6 +38,7 @@ impl<'a> Default for HorizontalBarView<'a> {
             keys: Vec::new(),
             colors: Color::color_scheme_10(),
             color_map: HashMap::new(),
+            color_scale: None,
             x_scale: None,
             y_scale: None,
             custom_data_label: String::new(),
@@ -52,13 +54,13 @@ impl<'a> HorizontalBarView<'a> {
     }
 
     /// Set the scale for the X dimension.
-    pub fn set_x_scale(mut self, scale: &'a impl Scale<f32>) -> Self {
+    pub fn set_x_scale(mut self, scale: &'a dyn Scale<f32>) -> Self {
         self.x_scale = Some(scale);
         self
     }
 
     /// Set the scale for the Y dimension.
-    pub fn set_y_scale(mut self, scale: &'a impl Scale<String>) -> Self {
+    pub fn set_y_scale(mut self, scale: &'a dyn Scale<String>) -> Self {
         self.y_scale = Some(scale);
         self
     }
@@ -81,6 +83,13 @@ impl<'a> HorizontalBarView<'a> {
         self
     }
 
+    /// Set a color scale to color each bar by its value instead of by its key.
+    /// The bars of a stacked bar chart are colored block by block.
+    pub fn set_color_scale(mut self, scale: &'a ScaleColor) -> Self {
+        self.color_scale = Some(scale);
+        self
+    }
+
     /// Set labels visibility.
     pub fn set_label_visibility(mut self, label_visibility: bool) -> Self {
         self.labels_visible = label_visibility;
@@ -115,14 +124,22 @@ impl<'a> HorizontalBarView<'a> {
     }
 
     /// Load and process a dataset of BarDatum points.
-    pub fn load_data(mut self, data: &[impl BarDatum]) -> Result<Self, String> {
+    pub fn load_data(mut self, data: &[impl BarDatum]) -> Result<Self, ChartError> {
         match self.x_scale {
             Some(scale) if scale.get_type() == ScaleType::Linear => {}
-            _ => return Err("The X axis scale should be a Band scale.".to_string()),
+            _ => {
+                return Err(ChartError::Scale(
+                    "The X axis scale should be a Linear scale.".to_string(),
+                ))
+            }
         }
         match self.y_scale {
             Some(scale) if scale.get_type() == ScaleType::Band => {}
-            _ => return Err("The Y axis scale should be a Linear scale.".to_string()),
+            _ => {
+                return Err(ChartError::Scale(
+                    "The Y axis scale should be a Band scale.".to_string(),
+                ))
+            }
         }
 
         // If no keys were explicitly provided, extract the keys from the data.
@@ -162,7 +179,7 @@ impl<'a> HorizontalBarView<'a> {
         for (category, key_value_pairs) in categories.iter_mut() {
             let mut value_acc = 0_f32;
             let mut bar_blocks = Vec::new();
-            let mut stacked_start = self.x_scale.unwrap().scale(&value_acc);
+            let mut stacked_start = self.x_scale.unwrap().scale(&value_acc)?;
             let mut stacked_end = stacked_start;
 
             for (key, value) in key_value_pairs.iter() {
@@ -170,16 +187,21 @@ impl<'a> HorizontalBarView<'a> {
 
                 if x_range_is_reversed {
                     stacked_end = stacked_start;
-                    stacked_start = self.x_scale.unwrap().scale(&value_acc);
+                    stacked_start = self.x_scale.unwrap().scale(&value_acc)?;
                 } else {
                     stacked_start = stacked_end;
-                    stacked_end = self.x_scale.unwrap().scale(&value_acc);
+                    stacked_end = self.x_scale.unwrap().scale(&value_acc)?;
                 }
+                let color = match self.color_scale {
+                    Some(color_scale) => color_scale.scale(*value)?.as_hex(),
+                    None => self.color_map.get(*key).unwrap().clone(),
+                };
                 bar_blocks.push(BarBlock::new(
                     stacked_start,
                     stacked_end,
                     *value,
-                    self.color_map.get(*key).unwrap().clone(),
+                    color,
+                    key.to_string(),
                 ));
             }
 
@@ -192,7 +214,7 @@ impl<'a> HorizontalBarView<'a> {
                 self.label_font_size,
                 self.rounding_precision,
                 self.y_scale.unwrap().bandwidth().unwrap(),
-                self.y_scale.unwrap().scale(category),
+                self.y_scale.unwrap().scale(category)?,
             );
             bars.push(bar);
         }
@@ -226,16 +248,17 @@ impl<'a> HorizontalBarView<'a> {
 }
 
 impl<'a> View<'a> for HorizontalBarView<'a> {
-    /// Generate the SVG representation of the view.
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new();
+    /// Draw the view on the given backend.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        backend.begin_group(&GroupOptions::new());
 
         for entry in self.entries.iter() {
-            let child_svg = entry.to_svg()?;
-            group.append(child_svg);
+            entry.draw(backend)?;
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 
     /// Return the legend entries that this view represents.
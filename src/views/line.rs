@@ -1,14 +1,14 @@
+use crate::backend::{Backend, GroupOptions};
 use crate::colors::Color;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
 use crate::components::scatter::{MarkerType, PointLabelPosition, ScatterPoint};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::views::datum::PointDatum;
 use crate::views::View;
 use crate::{LineSeries, Scale};
 use std::collections::HashMap;
 use std::fmt::Display;
-use svg::node::element::Group;
-use svg::node::Node;
 
 /// A View that represents data as a scatter plot.
 pub struct LineSeriesView<'a, T: Display, U: Display> {
@@ -51,13 +51,13 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
     }
 
     /// Set the scale for the X dimension.
-    pub fn set_x_scale(mut self, scale: &'a impl Scale<T>) -> Self {
+    pub fn set_x_scale(mut self, scale: &'a dyn Scale<T>) -> Self {
         self.x_scale = Some(scale);
         self
     }
 
     /// Set the scale for the Y dimension.
-    pub fn set_y_scale(mut self, scale: &'a impl Scale<U>) -> Self {
+    pub fn set_y_scale(mut self, scale: &'a dyn Scale<U>) -> Self {
         self.y_scale = Some(scale);
         self
     }
@@ -108,21 +108,21 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
     }
 
     /// Load and process a dataset of BarDatum points.
-    pub fn load_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, String> {
+    pub fn load_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, ChartError> {
         match self.x_scale {
             Some(_) => {}
             _ => {
-                return Err(
+                return Err(ChartError::Scale(
                     "Please provide a scale for the X dimension before loading data".to_string(),
-                )
+                ))
             }
         }
         match self.y_scale {
             Some(_) => {}
             _ => {
-                return Err(
+                return Err(ChartError::Scale(
                     "Please provide a scale for the Y dimension before loading data".to_string(),
-                )
+                ))
             }
         }
 
@@ -144,8 +144,8 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
                 .iter()
                 .filter(|datum| &datum.get_key() == key)
                 .map(|datum| {
-                    let scaled_x = self.x_scale.unwrap().scale(&datum.get_x());
-                    let scaled_y = self.y_scale.unwrap().scale(&datum.get_y());
+                    let scaled_x = self.x_scale.unwrap().scale(&datum.get_x())?;
+                    let scaled_y = self.y_scale.unwrap().scale(&datum.get_y())?;
                     let y_bandwidth_offset = {
                         if self.y_scale.unwrap().is_range_reversed() {
                             -self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
@@ -160,7 +160,7 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
                             self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
                         }
                     };
-                    ScatterPoint::new(
+                    Ok(ScatterPoint::new(
                         scaled_x + x_bandwidth_offset,
                         scaled_y + y_bandwidth_offset,
                         self.marker_type,
@@ -172,13 +172,14 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
                         true,
                         self.color_map.get(&datum.get_key()).unwrap().clone(),
                     )
+                    .set_key(datum.get_key()))
                 })
-                .collect::<Vec<ScatterPoint<T, U>>>();
+                .collect::<Result<Vec<ScatterPoint<T, U>>, ChartError>>()?;
 
-            self.entries.push(LineSeries::new(
-                points,
-                self.color_map.get(key).unwrap().clone(),
-            ));
+            self.entries.push(
+                LineSeries::new(points, self.color_map.get(key).unwrap().clone())
+                    .set_key(key.clone()),
+            );
         }
 
         Ok(self)
@@ -201,16 +202,17 @@ impl<'a, T: Display, U: Display> LineSeriesView<'a, T, U> {
 }
 
 impl<'a, T: Display, U: Display> View<'a> for LineSeriesView<'a, T, U> {
-    /// Generate the SVG representation of the view.
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new();
+    /// Draw the view on the given backend.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        backend.begin_group(&GroupOptions::new());
 
         for entry in self.entries.iter() {
-            let child_svg = entry.to_svg()?;
-            group.append(child_svg);
+            entry.draw(backend)?;
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 
     /// Return the legend entries that this view represents.
@@ -1,5 +1,8 @@
 use svg::node::element::Group;
+use crate::backend::svg::SvgBackend;
+use crate::backend::Backend;
 use crate::components::legend::LegendEntry;
+use crate::error::ChartError;
 
 pub mod vertical_bar;
 pub mod horizontal_bar;
@@ -10,7 +13,15 @@ pub mod area;
 
 /// A trait that defines a View of a dataset that can be rendered within a chart.
 pub trait View<'a> {
-    fn to_svg(&self) -> Result<Group, String>;
+    /// Draw the view on the given backend.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError>;
+
+    /// Generate the SVG representation of the view.
+    fn to_svg(&self) -> Result<Group, ChartError> {
+        let mut backend = SvgBackend::new();
+        self.draw(&mut backend)?;
+        Ok(backend.into_group())
+    }
 
     fn get_legend_entries(&self) -> Vec<LegendEntry>;
 }
@@ -1,14 +1,14 @@
+use crate::backend::{Backend, GroupOptions};
 use crate::colors::Color;
 use crate::components::legend::{LegendEntry, LegendMarkerType};
 use crate::components::scatter::{MarkerType, PointLabelPosition, ScatterPoint};
 use crate::components::DatumRepresentation;
+use crate::error::ChartError;
 use crate::views::datum::PointDatum;
 use crate::views::View;
-use crate::Scale;
+use crate::{Scale, ScaleColor};
 use std::collections::HashMap;
 use std::fmt::Display;
-use svg::node::element::Group;
-use svg::node::Node;
 
 /// A View that represents data as a scatter plot.
 pub struct ScatterView<'a, T: Display, U: Display> {
@@ -19,6 +19,7 @@ pub struct ScatterView<'a, T: Display, U: Display> {
     colors: Vec<Color>,
     keys: Vec<String>,
     color_map: HashMap<String, String>,
+    color_scale: Option<&'a ScaleColor>,
     x_scale: Option<&'a dyn Scale<T>>,
     y_scale: Option<&'a dyn Scale<U>>,
     custom_data_label: String,
@@ -35,6 +36,7 @@ impl<'a, T: Display, U: Display> Default for ScatterView<'a, T, U> {
             keys: Vec::new(),
             colors: Color::color_scheme_10(),
             color_map: HashMap::new(),
+            color_scale: None,
             x_scale: None,
             y_scale: None,
             custom_data_label: String::new(),
@@ -50,13 +52,13 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
     }
 
     /// Set the scale for the X dimension.
-    pub fn set_x_scale(mut self, scale: &'a impl Scale<T>) -> Self {
+    pub fn set_x_scale(mut self, scale: &'a dyn Scale<T>) -> Self {
         self.x_scale = Some(scale);
         self
     }
 
     /// Set the scale for the Y dimension.
-    pub fn set_y_scale(mut self, scale: &'a impl Scale<U>) -> Self {
+    pub fn set_y_scale(mut self, scale: &'a dyn Scale<U>) -> Self {
         self.y_scale = Some(scale);
         self
     }
@@ -85,6 +87,14 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
         self
     }
 
+    /// Set a color scale to color each point by its color value instead of by its key,
+    /// see `PointDatum::get_color_value`. Points without a color value are colored by their
+    /// Y value when it is a number, and keep the color of their key otherwise.
+    pub fn set_color_scale(mut self, scale: &'a ScaleColor) -> Self {
+        self.color_scale = Some(scale);
+        self
+    }
+
     /// Set labels visibility.
     pub fn set_label_visibility(mut self, label_visibility: bool) -> Self {
         self.labels_visible = label_visibility;
@@ -107,21 +117,21 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
     }
 
     /// Load and process a dataset of BarDatum points.
-    pub fn load_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, String> {
+    pub fn load_data(mut self, data: &[impl PointDatum<T, U>]) -> Result<Self, ChartError> {
         match self.x_scale {
             Some(_) => {}
             _ => {
-                return Err(
+                return Err(ChartError::Scale(
                     "Please provide a scale for the X dimension before loading data".to_string(),
-                )
+                ))
             }
         }
         match self.y_scale {
             Some(_) => {}
             _ => {
-                return Err(
+                return Err(ChartError::Scale(
                     "Please provide a scale for the Y dimension before loading data".to_string(),
-                )
+                ))
             }
         }
 
@@ -139,8 +149,8 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
         }
 
         for datum in data.iter() {
-            let scaled_x = self.x_scale.unwrap().scale(&datum.get_x());
-            let scaled_y = self.y_scale.unwrap().scale(&datum.get_y());
+            let scaled_x = self.x_scale.unwrap().scale(&datum.get_x())?;
+            let scaled_y = self.y_scale.unwrap().scale(&datum.get_y())?;
             let y_bandwidth_offset = {
                 if self.y_scale.unwrap().is_range_reversed() {
                     -self.y_scale.unwrap().bandwidth().unwrap() / 2_f32
@@ -155,18 +165,28 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
                     self.x_scale.unwrap().bandwidth().unwrap() / 2_f32
                 }
             };
-            self.entries.push(ScatterPoint::new(
-                scaled_x + x_bandwidth_offset,
-                scaled_y + y_bandwidth_offset,
-                self.marker_type,
-                5,
-                datum.get_x(),
-                datum.get_y(),
-                self.label_position,
-                self.labels_visible,
-                true,
-                self.color_map.get(&datum.get_key()).unwrap().clone(),
-            ));
+            let color_value = datum
+                .get_color_value()
+                .or_else(|| datum.get_y().to_string().parse::<f32>().ok());
+            let color = match (self.color_scale, color_value) {
+                (Some(color_scale), Some(value)) => color_scale.scale(value)?.as_hex(),
+                _ => self.color_map.get(&datum.get_key()).unwrap().clone(),
+            };
+            self.entries.push(
+                ScatterPoint::new(
+                    scaled_x + x_bandwidth_offset,
+                    scaled_y + y_bandwidth_offset,
+                    self.marker_type,
+                    5,
+                    datum.get_x(),
+                    datum.get_y(),
+                    self.label_position,
+                    self.labels_visible,
+                    true,
+                    color,
+                )
+                .set_key(datum.get_key()),
+            );
         }
 
         Ok(self)
@@ -189,16 +209,17 @@ impl<'a, T: Display, U: Display> ScatterView<'a, T, U> {
 }
 
 impl<'a, T: Display, U: Display> View<'a> for ScatterView<'a, T, U> {
-    /// Generate the SVG representation of the view.
-    fn to_svg(&self) -> Result<Group, String> {
-        let mut group = Group::new();
+    /// Draw the view on the given backend.
+    fn draw(&self, backend: &mut dyn Backend) -> Result<(), ChartError> {
+        backend.begin_group(&GroupOptions::new());
 
         for entry in self.entries.iter() {
-            let child_svg = entry.to_svg()?;
-            group.append(child_svg);
+            entry.draw(backend)?;
         }
 
-        Ok(group)
+        backend.end_group();
+
+        Ok(())
     }
 
     /// Return the legend entries that this view represents.
@@ -231,3 +252,53 @@ impl<'a, T: Display, U: Display> View<'a> for ScatterView<'a, T, U> {
         entries
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color, ScaleLinear};
+
+    #[test]
+    fn color_scale_fills_points_by_value() {
+        let x = ScaleLinear::<f64>::new()
+            .set_domain(vec![0_f64, 100_f64])
+            .set_range(vec![0, 100]);
+        let y = ScaleLinear::<f64>::new()
+            .set_domain(vec![0_f64, 100_f64])
+            .set_range(vec![100, 0]);
+        let color = ScaleColor::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_palette(Color::from_vec_of_hex_strings(vec!["#000000", "#ffffff"]));
+
+        // Data without its own color value is colored by its Y value.
+        let view = ScatterView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_color_scale(&color)
+            .load_data(&[(10_f64, 50_f64)])
+            .unwrap();
+        assert!(view
+            .to_svg()
+            .unwrap()
+            .to_string()
+            .contains("fill=\"#808080\""));
+
+        let x = ScaleLinear::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec![0, 100]);
+        let y = ScaleLinear::new()
+            .set_domain(vec![0_f32, 100_f32])
+            .set_range(vec![100, 0]);
+        let view = ScatterView::new()
+            .set_x_scale(&x)
+            .set_y_scale(&y)
+            .set_color_scale(&color)
+            .load_data(&[(10_f32, 50_f32, 100_f32)])
+            .unwrap();
+        assert!(view
+            .to_svg()
+            .unwrap()
+            .to_string()
+            .contains("fill=\"#ffffff\""));
+    }
+}